@@ -1,62 +1,415 @@
-use crate::error::Error;
-use crate::events::{self, EventListener};
-use crate::input::{Button, INPUT_STATE};
+use crate::control::{AppControl, WindowCommand};
+use crate::error::{Error, ErrorKind};
+use crate::events::{
+	self, EventBox, EventBus, EventCategory, EventListener, EventQueue, EventRecorder, EventSender,
+	Recording,
+};
+use crate::input::{self, Button, GamepadAxis, GamepadButton, GamepadId, GamepadSource, Key};
 use crate::layers::{Layer, LayerStack};
+use crate::time::FrameClock;
 use crate::vecs::Vec2;
-use crate::window;
+use crate::window::{self, WindowConfig, WindowId};
+use crate::Float;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 
 pub struct Application {
-	is_running: bool,
-	window: window::Window,
+	control: AppControl,
+	// The main window comes first, closing it stops the application
+	windows: Vec<window::Window>,
+	closed_windows: Vec<WindowId>,
+	event_coalescing: bool,
+	gamepad_sources: Vec<Box<dyn GamepadSource>>,
 	layer_stack: LayerStack,
+	event_bus: EventBus,
+	fixed_update_rate: Float,
+	max_fixed_steps: u32,
+	recorder: Option<EventRecorder>,
 }
 
 static APPLICATION_EXISTS: AtomicBool = AtomicBool::new(false);
 impl Application {
-	pub fn new(title: &str, width: u32, height: u32, vsync: bool) -> Result<Application, Error> {
+	pub fn new(config: WindowConfig) -> Result<Application, Error> {
 		if APPLICATION_EXISTS.swap(true, Ordering::Relaxed) {
-			return Err(Error {});
+			crate::rame_core_error!("tried to create a second application");
+			return Err(Error::new(ErrorKind::AlreadyRunning));
 		}
 
-		let application = Application {
-			is_running: true,
-			window: window::Window::new(title, width, height, vsync)?,
-			layer_stack: LayerStack::new(),
+		let window = match window::Window::new(config) {
+			Ok(window) => window,
+			Err(err) => {
+				APPLICATION_EXISTS.store(false, Ordering::Relaxed);
+				return Err(err);
+			}
 		};
 
-		Ok(application)
+		Ok(Application::from_window(window))
 	}
 
-	pub fn start(mut self) {
-		self.window.clear_color(0.15, 0.1, 0.9);
+	/// Creates an application around an already created window, e.g. one
+	/// using a `HeadlessBackend`.
+	pub fn with_window(window: window::Window) -> Result<Application, Error> {
+		if APPLICATION_EXISTS.swap(true, Ordering::Relaxed) {
+			crate::rame_core_error!("tried to create a second application");
+			return Err(Error::new(ErrorKind::AlreadyRunning));
+		}
 
-		while self.is_running {
-			self.window.on_update();
-			while let Some(mut event) = self.window.pop_event() {
-				event.dispatch(&mut self);
-				for layer in self.layer_stack.iter_mut().rev() {
-					event.dispatch(layer.as_event_listener());
-					if event.is_handled() {
-						break;
+		Ok(Application::from_window(window))
+	}
+
+	/// Creates a headless application that replays the session recorded at
+	/// `path`, see `record_to`.
+	pub fn replay<P: AsRef<Path>>(path: P) -> Result<Application, Error> {
+		let recording = Recording::load(path)?;
+		Application::with_window(window::Window::replay(recording))
+	}
+
+	fn from_window(window: window::Window) -> Application {
+		Application {
+			control: AppControl::new(window.get_id()),
+			windows: vec![window],
+			closed_windows: Vec::new(),
+			event_coalescing: false,
+			gamepad_sources: Vec::new(),
+			layer_stack: LayerStack::new(),
+			event_bus: EventBus::new(),
+			fixed_update_rate: 60.0,
+			max_fixed_steps: 5,
+			recorder: None,
+		}
+	}
+
+	/// Sets how many times per second `on_fixed_update` is called. Defaults to 60.
+	pub fn set_fixed_update_rate(&mut self, hz: Float) {
+		assert!(hz > 0.0, "fixed update rate must be positive");
+		self.fixed_update_rate = hz;
+	}
+
+	/// Sets how many fixed updates may run in a single frame to catch up
+	/// after a slow frame. Any time beyond that is dropped. Defaults to 5.
+	pub fn set_max_fixed_steps(&mut self, steps: u32) {
+		self.max_fixed_steps = steps;
+	}
+
+	/// Records every window event and the duration of every frame to the file
+	/// at `path`, which can later be played back with `Application::replay`.
+	pub fn record_to<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
+		let window = self.window();
+		let (title, width, height) = (window.get_title(), window.get_width(), window.get_height());
+		let recorder = EventRecorder::create(path, title, width, height, window.get_scale_factor())?;
+		self.recorder = Some(recorder);
+		Ok(())
+	}
+
+	/// Runs the main loop until the application is quit, by closing the main
+	/// window or through `AppControl`. Returns the exit code, or the error
+	/// that stopped the application.
+	pub fn start(mut self) -> Result<i32, Error> {
+		crate::rame_core_info!("starting application");
+		let result = self.run();
+		self.record(|recorder| recorder.flush());
+		match &result {
+			Ok(code) => crate::rame_core_info!("application stopped with exit code {}", code),
+			Err(err) => crate::rame_core_error!("application stopped: {}", err),
+		}
+		result
+	}
+
+	fn run(&mut self) -> Result<i32, Error> {
+		let mut clock = FrameClock::new();
+		let mut fixed_clock = FrameClock::new();
+		let mut accumulator: Float = 0.0;
+
+		loop {
+			if let Some(code) = self.control.get_exit_code() {
+				return Ok(code);
+			}
+			let frame_start = Instant::now();
+			self.run_window_commands();
+			let delta = match self.window_mut().frame_delta() {
+				Some(delta) => delta,
+				None => clock.measure(),
+			};
+			let time = clock.advance(delta);
+			self.record(|recorder| recorder.begin_frame(delta));
+			input::state_mut().begin_frame(time.elapsed);
+
+			let text_input = self.layer_stack.iter().any(|layer| layer.wants_text_input());
+			for window in &mut self.windows {
+				window.set_text_input(text_input);
+				window.on_update();
+			}
+			for i in 0..self.windows.len() {
+				// Layers handling the window's events, e.g. recreating render
+				// targets on a framebuffer resize, do so in its context
+				if let Err(err) = self.windows[i].make_current() {
+					crate::rame_core_error!("failed to make window {} current: {}", self.windows[i].get_id(), err);
+				}
+				window::set_current_window(Some(self.windows[i].get_id()));
+				while let Some(mut event) = self.windows[i].pop_event() {
+					// Replays only have a main window
+					if i == 0 {
+						self.record(|recorder| recorder.record(&*event));
 					}
+					self.dispatch(&mut event);
 				}
 			}
+			window::set_current_window(None);
+			let mut gamepad_events = EventQueue::new();
+			gamepad_events.set_coalescing(self.event_coalescing);
+			for source in &mut self.gamepad_sources {
+				source.poll(&mut gamepad_events);
+			}
+			// Recorded along with the main window's events, which is where
+			// replays deliver them from
+			for mut event in gamepad_events {
+				self.record(|recorder| recorder.record(&*event));
+				self.dispatch(&mut event);
+			}
+			for id in std::mem::take(&mut self.closed_windows) {
+				self.close_window(id);
+			}
+			for mut event in self.event_bus.take(time.delta) {
+				self.dispatch(&mut event);
+			}
 
-			let mut event = events::AppUpdateEvent::new();
-			for layer in self.layer_stack.iter_mut() {
-				event.dispatch(layer.as_event_listener());
+			let fixed_delta = 1.0 / self.fixed_update_rate;
+			let stepping = self.control.is_paused();
+			if self.control.take_step() {
+				// A single step runs exactly one fixed update
+				let max_fixed_steps = if stepping {
+					accumulator = accumulator.max(fixed_delta);
+					1
+				} else {
+					accumulator += time.delta;
+					self.max_fixed_steps
+				};
+				let mut steps = 0;
+				while accumulator >= fixed_delta && steps < max_fixed_steps {
+					let fixed_time = fixed_clock.advance_secs(fixed_delta);
+					let mut event = events::AppFixedUpdateEvent::new(fixed_time);
+					for layer in self.layer_stack.iter_mut() {
+						event.dispatch(layer.as_event_listener());
+					}
+					accumulator -= fixed_delta;
+					steps += 1;
+				}
+				if accumulator >= fixed_delta {
+					accumulator %= fixed_delta;
+				}
+
+				let mut event = events::AppUpdateEvent::new(time);
+				for layer in self.layer_stack.iter_mut() {
+					event.dispatch(layer.as_event_listener());
+				}
 			}
+			let alpha = accumulator / fixed_delta;
+
+			// Layers render once per window, see `window::get_current_window`
+			for window in &mut self.windows {
+				// Minimized windows have nothing to render to
+				if window.is_minimized() {
+					continue;
+				}
+				if let Err(err) = window.make_current() {
+					crate::rame_core_error!("failed to render window {}: {}", window.get_id(), err);
+					continue;
+				}
+				window.clear_color(0.15, 0.1, 0.9);
+				window.clear_screen();
 
-			self.window.clear_screen();
+				window::set_current_window(Some(window.get_id()));
+				let mut event = events::AppRenderEvent::new(alpha);
+				for layer in self.layer_stack.iter_mut() {
+					event.dispatch(layer.as_event_listener());
+				}
+				window::set_current_window(None);
 
-			let mut event = events::AppRenderEvent::new();
-			for layer in self.layer_stack.iter_mut() {
-				event.dispatch(layer.as_event_listener());
+				window.swap_buffers()?;
 			}
+			// Without swapping buffers nothing limits the frame rate
+			if self.windows.iter().all(|window| window.is_minimized()) {
+				thread::sleep(Duration::from_millis(10));
+			}
+			if let Some(fps) = self.control.get_max_frame_rate() {
+				let frame_time = Duration::from_secs_f64(1.0 / fps);
+				let elapsed = frame_start.elapsed();
+				if elapsed < frame_time {
+					thread::sleep(frame_time - elapsed);
+				}
+			}
+		}
+	}
 
-			self.window.swap_buffers().unwrap();
+	/// Applies the changes requested through `AppControl`, opening windows
+	/// first so they can be changed right away and closing them last
+	fn run_window_commands(&mut self) {
+		for (id, config) in self.control.take_opened_windows() {
+			match window::Window::with_id(id, config) {
+				Ok(window) => {
+					self.add_window(window);
+				}
+				Err(err) => crate::rame_core_error!("failed to open window {}: {}", id, err),
+			}
 		}
+		for (id, command) in self.control.take_window_commands() {
+			let window = match self.get_window_mut(id) {
+				Some(window) => window,
+				None => {
+					crate::rame_core_warn!("ignored a command for closed window {}", id);
+					continue;
+				}
+			};
+			match command {
+				WindowCommand::Vsync(vsync) => {
+					if let Err(err) = window.set_vsync(vsync) {
+						crate::rame_core_error!("failed to change vsync of window {}: {}", id, err);
+					}
+				}
+				WindowCommand::Title(title) => window.set_title(&title),
+				WindowCommand::Size(width, height) => window.set_size(width, height),
+				WindowCommand::Position(x, y) => window.set_position(x, y),
+				WindowCommand::Resizable(resizable) => window.set_resizable(resizable),
+				WindowCommand::Mode(mode) => window.set_mode(mode),
+				WindowCommand::MinSize(size) => window.set_min_size(size),
+				WindowCommand::MaxSize(size) => window.set_max_size(size),
+				WindowCommand::Icon(icon) => window.set_icon(icon),
+				WindowCommand::CursorIcon(icon) => window.set_cursor_icon(icon),
+				WindowCommand::CursorVisible(visible) => window.set_cursor_visible(visible),
+				WindowCommand::CursorGrabbed(grabbed) => {
+					if let Err(err) = window.set_cursor_grabbed(grabbed) {
+						crate::rame_core_error!("failed to grab cursor in window {}: {}", id, err);
+					}
+				}
+				WindowCommand::RelativeMouse(enabled) => {
+					if let Err(err) = window.set_relative_mouse(enabled) {
+						crate::rame_core_error!("failed to change relative mouse of window {}: {}", id, err);
+					}
+				}
+				WindowCommand::CursorPosition(position) => {
+					if let Err(err) = window.set_cursor_position(position) {
+						crate::rame_core_error!("failed to move cursor in window {}: {}", id, err);
+					}
+				}
+			}
+		}
+		for id in self.control.take_closed_windows() {
+			self.close_window(id);
+		}
+	}
+
+	/// Stops recording if writing to the recording fails
+	fn record<F: FnOnce(&mut EventRecorder) -> Result<(), Error>>(&mut self, f: F) {
+		if let Some(recorder) = &mut self.recorder {
+			if let Err(err) = f(recorder) {
+				crate::rame_core_error!("stopped recording: {}", err);
+				self.recorder = None;
+			}
+		}
+	}
+
+	/// Dispatches `event` to the application itself and then to the layers
+	/// subscribed to it, from the top down, until one of them handles it.
+	fn dispatch(&mut self, event: &mut EventBox) {
+		crate::rame_core_trace!("dispatching {}", event);
+		event.dispatch(self);
+		for (i, layer) in self.layer_stack.iter_mut().enumerate().rev() {
+			if !event.is_in_category(layer.event_filter()) {
+				continue;
+			}
+			if event.is_in_category(EventCategory::TEXT) && !layer.wants_text_input() {
+				continue;
+			}
+			if let Some(payload) = event.payload_type() {
+				if !layer.wants_custom_event(payload) {
+					continue;
+				}
+			}
+			event.dispatch(layer.as_event_listener());
+			if event.is_handled() {
+				crate::rame_core_trace!("event handled by layer {}", i);
+				break;
+			}
+		}
+	}
+
+	/// The main window, the one the application was created with
+	pub fn window(&self) -> &window::Window {
+		&self.windows[0]
+	}
+
+	pub fn window_mut(&mut self) -> &mut window::Window {
+		&mut self.windows[0]
+	}
+
+	pub fn get_window(&self, id: WindowId) -> Option<&window::Window> {
+		self.windows.iter().find(|window| window.get_id() == id)
+	}
+
+	pub fn get_window_mut(&mut self, id: WindowId) -> Option<&mut window::Window> {
+		self.windows.iter_mut().find(|window| window.get_id() == id)
+	}
+
+	/// Opens another window. Its events go through the same layers as the
+	/// main window's, see `window::get_current_window`.
+	pub fn open_window(&mut self, config: WindowConfig) -> Result<WindowId, Error> {
+		let window = window::Window::new(config)?;
+		Ok(self.add_window(window))
+	}
+
+	/// Adds an already created window, e.g. one using a `HeadlessBackend`.
+	pub fn add_window(&mut self, mut window: window::Window) -> WindowId {
+		crate::rame_core_info!("opened window {} \"{}\"", window.get_id(), window.get_title());
+		window.set_event_coalescing(self.event_coalescing);
+		let id = window.get_id();
+		self.windows.push(window);
+		id
+	}
+
+	/// Closing the main window stops the application instead.
+	pub fn close_window(&mut self, id: WindowId) {
+		if id == self.window().get_id() {
+			self.control.quit();
+			return;
+		}
+		if let Some(i) = self.windows.iter().position(|window| window.get_id() == id) {
+			let window = self.windows.remove(i);
+			crate::rame_core_info!("closed window {} \"{}\"", id, window.get_title());
+		}
+	}
+
+	/// A handle for quitting, pausing and stepping the application, limiting
+	/// its frame rate and changing its windows
+	pub fn control(&self) -> AppControl {
+		self.control.clone()
+	}
+
+	/// A handle for publishing custom events to the layers of this application
+	pub fn event_bus(&self) -> EventBus {
+		self.event_bus.clone()
+	}
+
+	/// A handle for publishing events to the layers of this application from
+	/// other threads
+	pub fn event_sender(&self) -> EventSender {
+		self.event_bus.sender()
+	}
+
+	/// Whether redundant window events, like several mouse moves within a
+	/// frame, are merged. Disabled by default.
+	pub fn set_event_coalescing(&mut self, coalescing: bool) {
+		self.event_coalescing = coalescing;
+		for window in &mut self.windows {
+			window.set_event_coalescing(coalescing);
+		}
+	}
+
+	/// Adds a source of gamepad events, e.g. a `VirtualGamepad`. Gamepads
+	/// aren't tied to a window.
+	pub fn add_gamepad_source(&mut self, source: Box<dyn GamepadSource>) {
+		self.gamepad_sources.push(source);
 	}
 
 	pub fn push_layer(&mut self, layer: Box<dyn Layer>) {
@@ -70,256 +423,65 @@ impl Application {
 
 impl EventListener for Application {
 	fn on_window_closed(&mut self) {
-		self.is_running = false;
-	}
-	fn on_mouse_move(&mut self, position: Vec2) -> bool {
-		unsafe {
-			INPUT_STATE.mouse_position = position;
+		match window::get_current_window() {
+			// Closed after all events are dispatched, as later events may
+			// still belong to the window
+			Some(id) => self.closed_windows.push(id),
+			None => self.control.quit(),
 		}
+	}
+	fn on_mouse_move(&mut self, position: Vec2, scale_factor: Float) -> bool {
+		input::state_mut().set_mouse_position(position, scale_factor, window::get_current_window());
 		false
 	}
-	fn on_key_press(&mut self, button: Button, _: bool) -> bool {
-		use Button::*;
-		unsafe {
-			match button {
-				Unknown => {}
-				MouseLeft => INPUT_STATE.mouse_left = true,
-				MouseRight => INPUT_STATE.mouse_right = true,
-				MouseMiddle => INPUT_STATE.mouse_middle = true,
-				Mouse4 => INPUT_STATE.mouse4 = true,
-				Mouse5 => INPUT_STATE.mouse5 = true,
-				Mouse6 => INPUT_STATE.mouse6 = true,
-				Mouse7 => INPUT_STATE.mouse7 = true,
-				Mouse8 => INPUT_STATE.mouse8 = true,
-				LShift => INPUT_STATE.l_shift = true,
-				LCtrl => INPUT_STATE.l_ctrl = true,
-				LAlt => INPUT_STATE.l_alt = true,
-				LSuper => INPUT_STATE.l_super = true,
-				RShift => INPUT_STATE.r_shift = true,
-				RCtrl => INPUT_STATE.r_ctrl = true,
-				RAlt => INPUT_STATE.r_alt = true,
-				RSuper => INPUT_STATE.r_super = true,
-				Tab => INPUT_STATE.tab = true,
-				CapsLock => INPUT_STATE.caps_lock = true,
-				Backspace => INPUT_STATE.backspace = true,
-				Esc => INPUT_STATE.esc = true,
-				F1 => INPUT_STATE.f1 = true,
-				F2 => INPUT_STATE.f2 = true,
-				F3 => INPUT_STATE.f3 = true,
-				F4 => INPUT_STATE.f4 = true,
-				F5 => INPUT_STATE.f5 = true,
-				F6 => INPUT_STATE.f6 = true,
-				F7 => INPUT_STATE.f7 = true,
-				F8 => INPUT_STATE.f8 = true,
-				F9 => INPUT_STATE.f9 = true,
-				F10 => INPUT_STATE.f10 = true,
-				F11 => INPUT_STATE.f11 = true,
-				F12 => INPUT_STATE.f12 = true,
-				PrintScreen => INPUT_STATE.print_screen = true,
-				ScrollLock => INPUT_STATE.scroll_lock = true,
-				Pause => INPUT_STATE.pause = true,
-				Insert => INPUT_STATE.insert = true,
-				Delete => INPUT_STATE.delete = true,
-				Home => INPUT_STATE.home = true,
-				End => INPUT_STATE.end = true,
-				PgUp => INPUT_STATE.pg_up = true,
-				PgDown => INPUT_STATE.pg_down = true,
-				Menu => INPUT_STATE.menu = true,
-				ArrowLeft => INPUT_STATE.arrow_left = true,
-				ArrowUp => INPUT_STATE.arrow_up = true,
-				ArrowRight => INPUT_STATE.arrow_right = true,
-				ArrowDown => INPUT_STATE.arrow_down = true,
-				Tilde => INPUT_STATE.tilde = true,
-				Num1 => INPUT_STATE.num1 = true,
-				Num2 => INPUT_STATE.num2 = true,
-				Num3 => INPUT_STATE.num3 = true,
-				Num4 => INPUT_STATE.num4 = true,
-				Num5 => INPUT_STATE.num5 = true,
-				Num6 => INPUT_STATE.num6 = true,
-				Num7 => INPUT_STATE.num7 = true,
-				Num8 => INPUT_STATE.num8 = true,
-				Num9 => INPUT_STATE.num9 = true,
-				Num0 => INPUT_STATE.num0 = true,
-				Q => INPUT_STATE.q = true,
-				W => INPUT_STATE.w = true,
-				E => INPUT_STATE.e = true,
-				R => INPUT_STATE.r = true,
-				T => INPUT_STATE.t = true,
-				Y => INPUT_STATE.y = true,
-				U => INPUT_STATE.u = true,
-				I => INPUT_STATE.i = true,
-				O => INPUT_STATE.o = true,
-				P => INPUT_STATE.p = true,
-				A => INPUT_STATE.a = true,
-				S => INPUT_STATE.s = true,
-				D => INPUT_STATE.d = true,
-				F => INPUT_STATE.f = true,
-				G => INPUT_STATE.g = true,
-				H => INPUT_STATE.h = true,
-				J => INPUT_STATE.j = true,
-				K => INPUT_STATE.k = true,
-				L => INPUT_STATE.l = true,
-				Z => INPUT_STATE.z = true,
-				X => INPUT_STATE.x = true,
-				C => INPUT_STATE.c = true,
-				V => INPUT_STATE.v = true,
-				B => INPUT_STATE.b = true,
-				N => INPUT_STATE.n = true,
-				M => INPUT_STATE.m = true,
-				Space => INPUT_STATE.space = true,
-				Minus => INPUT_STATE.minus = true,
-				Equals => INPUT_STATE.equals = true,
-				BracketLeft => INPUT_STATE.bracket_left = true,
-				BracketRight => INPUT_STATE.bracket_right = true,
-				Backslash => INPUT_STATE.backslash = true,
-				Semicolon => INPUT_STATE.semicolon = true,
-				Apostrophe => INPUT_STATE.apostrophe = true,
-				Enter => INPUT_STATE.enter = true,
-				Comma => INPUT_STATE.comma = true,
-				Period => INPUT_STATE.period = true,
-				Slash => INPUT_STATE.slash = true,
-				NumPad0 => INPUT_STATE.numpad0 = true,
-				NumPad1 => INPUT_STATE.numpad1 = true,
-				NumPad2 => INPUT_STATE.numpad2 = true,
-				NumPad3 => INPUT_STATE.numpad3 = true,
-				NumPad4 => INPUT_STATE.numpad4 = true,
-				NumPad5 => INPUT_STATE.numpad5 = true,
-				NumPad6 => INPUT_STATE.numpad6 = true,
-				NumPad7 => INPUT_STATE.numpad7 = true,
-				NumPad8 => INPUT_STATE.numpad8 = true,
-				NumPad9 => INPUT_STATE.numpad9 = true,
-				NumLock => INPUT_STATE.numlock = true,
-				NumPadDec => INPUT_STATE.numpad_dec = true,
-				NumPadDiv => INPUT_STATE.numpad_div = true,
-				NumPadMult => INPUT_STATE.numpad_mult = true,
-				NumPadSub => INPUT_STATE.numpad_sub = true,
-				NumPadAdd => INPUT_STATE.numpad_add = true,
-				NumPadEq => INPUT_STATE.numpad_eq = true,
-				NumPadEnter => INPUT_STATE.numpad_enter = true,
-			}
-		}
+	fn on_cursor_enter(&mut self) {
+		input::state_mut().enter_window(window::get_current_window());
+	}
+	fn on_cursor_leave(&mut self) {
+		input::state_mut().leave_window(window::get_current_window());
+	}
+	fn on_key_press(&mut self, key: Key, _: bool) -> bool {
+		input::state_mut().press_key(key);
 		false
 	}
-	fn on_key_release(&mut self, button: Button) -> bool {
-		use Button::*;
-		unsafe {
-			match button {
-				Unknown => {}
-				MouseLeft => INPUT_STATE.mouse_left = false,
-				MouseRight => INPUT_STATE.mouse_right = false,
-				MouseMiddle => INPUT_STATE.mouse_middle = false,
-				Mouse4 => INPUT_STATE.mouse4 = false,
-				Mouse5 => INPUT_STATE.mouse5 = false,
-				Mouse6 => INPUT_STATE.mouse6 = false,
-				Mouse7 => INPUT_STATE.mouse7 = false,
-				Mouse8 => INPUT_STATE.mouse8 = false,
-				LShift => INPUT_STATE.l_shift = false,
-				LCtrl => INPUT_STATE.l_ctrl = false,
-				LAlt => INPUT_STATE.l_alt = false,
-				LSuper => INPUT_STATE.l_super = false,
-				RShift => INPUT_STATE.r_shift = false,
-				RCtrl => INPUT_STATE.r_ctrl = false,
-				RAlt => INPUT_STATE.r_alt = false,
-				RSuper => INPUT_STATE.r_super = false,
-				Tab => INPUT_STATE.tab = false,
-				CapsLock => INPUT_STATE.caps_lock = false,
-				Backspace => INPUT_STATE.backspace = false,
-				Esc => INPUT_STATE.esc = false,
-				F1 => INPUT_STATE.f1 = false,
-				F2 => INPUT_STATE.f2 = false,
-				F3 => INPUT_STATE.f3 = false,
-				F4 => INPUT_STATE.f4 = false,
-				F5 => INPUT_STATE.f5 = false,
-				F6 => INPUT_STATE.f6 = false,
-				F7 => INPUT_STATE.f7 = false,
-				F8 => INPUT_STATE.f8 = false,
-				F9 => INPUT_STATE.f9 = false,
-				F10 => INPUT_STATE.f10 = false,
-				F11 => INPUT_STATE.f11 = false,
-				F12 => INPUT_STATE.f12 = false,
-				PrintScreen => INPUT_STATE.print_screen = false,
-				ScrollLock => INPUT_STATE.scroll_lock = false,
-				Pause => INPUT_STATE.pause = false,
-				Insert => INPUT_STATE.insert = false,
-				Delete => INPUT_STATE.delete = false,
-				Home => INPUT_STATE.home = false,
-				End => INPUT_STATE.end = false,
-				PgUp => INPUT_STATE.pg_up = false,
-				PgDown => INPUT_STATE.pg_down = false,
-				Menu => INPUT_STATE.menu = false,
-				ArrowLeft => INPUT_STATE.arrow_left = false,
-				ArrowUp => INPUT_STATE.arrow_up = false,
-				ArrowRight => INPUT_STATE.arrow_right = false,
-				ArrowDown => INPUT_STATE.arrow_down = false,
-				Tilde => INPUT_STATE.tilde = false,
-				Num1 => INPUT_STATE.num1 = false,
-				Num2 => INPUT_STATE.num2 = false,
-				Num3 => INPUT_STATE.num3 = false,
-				Num4 => INPUT_STATE.num4 = false,
-				Num5 => INPUT_STATE.num5 = false,
-				Num6 => INPUT_STATE.num6 = false,
-				Num7 => INPUT_STATE.num7 = false,
-				Num8 => INPUT_STATE.num8 = false,
-				Num9 => INPUT_STATE.num9 = false,
-				Num0 => INPUT_STATE.num0 = false,
-				Q => INPUT_STATE.q = false,
-				W => INPUT_STATE.w = false,
-				E => INPUT_STATE.e = false,
-				R => INPUT_STATE.r = false,
-				T => INPUT_STATE.t = false,
-				Y => INPUT_STATE.y = false,
-				U => INPUT_STATE.u = false,
-				I => INPUT_STATE.i = false,
-				O => INPUT_STATE.o = false,
-				P => INPUT_STATE.p = false,
-				A => INPUT_STATE.a = false,
-				S => INPUT_STATE.s = false,
-				D => INPUT_STATE.d = false,
-				F => INPUT_STATE.f = false,
-				G => INPUT_STATE.g = false,
-				H => INPUT_STATE.h = false,
-				J => INPUT_STATE.j = false,
-				K => INPUT_STATE.k = false,
-				L => INPUT_STATE.l = false,
-				Z => INPUT_STATE.z = false,
-				X => INPUT_STATE.x = false,
-				C => INPUT_STATE.c = false,
-				V => INPUT_STATE.v = false,
-				B => INPUT_STATE.b = false,
-				N => INPUT_STATE.n = false,
-				M => INPUT_STATE.m = false,
-				Space => INPUT_STATE.space = false,
-				Minus => INPUT_STATE.minus = false,
-				Equals => INPUT_STATE.equals = false,
-				BracketLeft => INPUT_STATE.bracket_left = false,
-				BracketRight => INPUT_STATE.bracket_right = false,
-				Backslash => INPUT_STATE.backslash = false,
-				Semicolon => INPUT_STATE.semicolon = false,
-				Apostrophe => INPUT_STATE.apostrophe = false,
-				Enter => INPUT_STATE.enter = false,
-				Comma => INPUT_STATE.comma = false,
-				Period => INPUT_STATE.period = false,
-				Slash => INPUT_STATE.slash = false,
-				NumPad0 => INPUT_STATE.numpad0 = false,
-				NumPad1 => INPUT_STATE.numpad1 = false,
-				NumPad2 => INPUT_STATE.numpad2 = false,
-				NumPad3 => INPUT_STATE.numpad3 = false,
-				NumPad4 => INPUT_STATE.numpad4 = false,
-				NumPad5 => INPUT_STATE.numpad5 = false,
-				NumPad6 => INPUT_STATE.numpad6 = false,
-				NumPad7 => INPUT_STATE.numpad7 = false,
-				NumPad8 => INPUT_STATE.numpad8 = false,
-				NumPad9 => INPUT_STATE.numpad9 = false,
-				NumLock => INPUT_STATE.numlock = false,
-				NumPadDec => INPUT_STATE.numpad_dec = false,
-				NumPadDiv => INPUT_STATE.numpad_div = false,
-				NumPadMult => INPUT_STATE.numpad_mult = false,
-				NumPadSub => INPUT_STATE.numpad_sub = false,
-				NumPadAdd => INPUT_STATE.numpad_add = false,
-				NumPadEq => INPUT_STATE.numpad_eq = false,
-				NumPadEnter => INPUT_STATE.numpad_enter = false,
-			}
-		}
+	fn on_key_release(&mut self, key: Key) -> bool {
+		input::state_mut().release_key(key);
+		false
+	}
+	fn on_mouse_press(&mut self, button: Button) -> bool {
+		input::state_mut().press(button);
+		false
+	}
+	fn on_mouse_release(&mut self, button: Button) -> bool {
+		input::state_mut().release(button);
+		false
+	}
+	fn on_mouse_motion(&mut self, delta: Vec2) -> bool {
+		input::state_mut().move_mouse(delta);
+		false
+	}
+	fn on_mouse_scroll(&mut self, delta: Vec2) -> bool {
+		input::state_mut().scroll(delta);
+		false
+	}
+	fn on_gamepad_connected(&mut self, id: GamepadId) {
+		crate::rame_core_info!("gamepad {} connected", id.0);
+		input::state_mut().connect_gamepad(id);
+	}
+	fn on_gamepad_disconnected(&mut self, id: GamepadId) {
+		crate::rame_core_info!("gamepad {} disconnected", id.0);
+		input::state_mut().disconnect_gamepad(id);
+	}
+	fn on_gamepad_press(&mut self, id: GamepadId, button: GamepadButton) -> bool {
+		input::state_mut().press_gamepad(id, button);
+		false
+	}
+	fn on_gamepad_release(&mut self, id: GamepadId, button: GamepadButton) -> bool {
+		input::state_mut().release_gamepad(id, button);
+		false
+	}
+	fn on_gamepad_axis(&mut self, id: GamepadId, axis: GamepadAxis, value: Float) -> bool {
+		input::state_mut().move_gamepad_axis(id, axis, value);
 		false
 	}
 }
@@ -329,3 +491,332 @@ impl Drop for Application {
 		APPLICATION_EXISTS.store(false, Ordering::Relaxed);
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::time::Timestep;
+	use crate::window::HeadlessBackend;
+	use crate::events::CustomEvent;
+	use std::any::TypeId;
+	use std::cell::{Cell, RefCell};
+	use std::rc::Rc;
+
+	struct CountingLayer {
+		updates: Rc<Cell<u32>>,
+		quit_after: Option<(AppControl, u32)>,
+	}
+
+	impl EventListener for CountingLayer {
+		fn on_update(&mut self, _: Timestep) {
+			self.updates.set(self.updates.get() + 1);
+			if let Some((control, updates)) = &self.quit_after {
+				if self.updates.get() == *updates {
+					control.quit_with(3);
+				}
+			}
+		}
+	}
+
+	impl Layer for CountingLayer {}
+
+	/// Logs the fixed updates and the alpha of every rendered frame
+	struct TimingLayer {
+		fixed_updates: u32,
+		frames: Rc<RefCell<Vec<(u32, Float)>>>,
+	}
+
+	impl EventListener for TimingLayer {
+		fn on_fixed_update(&mut self, _: Timestep) {
+			self.fixed_updates += 1;
+		}
+
+		fn on_render(&mut self, alpha: Float) {
+			self.frames.borrow_mut().push((self.fixed_updates, alpha));
+			self.fixed_updates = 0;
+		}
+	}
+
+	impl Layer for TimingLayer {}
+
+	type Script = Box<dyn FnMut(usize, &AppControl)>;
+
+	/// Logs the updates and fixed updates of every rendered frame, running
+	/// `script` with the frame's index after logging it
+	struct ScriptedLayer {
+		control: AppControl,
+		script: Script,
+		updates: u32,
+		fixed_updates: u32,
+		frames: Rc<RefCell<Vec<(u32, u32)>>>,
+	}
+
+	impl EventListener for ScriptedLayer {
+		fn on_update(&mut self, _: Timestep) {
+			self.updates += 1;
+		}
+
+		fn on_fixed_update(&mut self, _: Timestep) {
+			self.fixed_updates += 1;
+		}
+
+		fn on_render(&mut self, _: Float) {
+			let mut frames = self.frames.borrow_mut();
+			frames.push((self.updates, self.fixed_updates));
+			self.updates = 0;
+			self.fixed_updates = 0;
+			(self.script)(frames.len() - 1, &self.control);
+		}
+	}
+
+	impl Layer for ScriptedLayer {}
+
+	/// Logs its name for every event dispatched to it
+	struct FilteredLayer {
+		name: &'static str,
+		filter: EventCategory,
+		payload: Option<TypeId>,
+		text_input: bool,
+		handles: bool,
+		log: Rc<RefCell<Vec<&'static str>>>,
+	}
+
+	impl FilteredLayer {
+		fn new(name: &'static str, log: &Rc<RefCell<Vec<&'static str>>>) -> FilteredLayer {
+			FilteredLayer {
+				name,
+				filter: EventCategory::ALL,
+				payload: None,
+				text_input: false,
+				handles: false,
+				log: log.clone(),
+			}
+		}
+	}
+
+	impl EventListener for FilteredLayer {
+		fn on_window_move(&mut self, _: i32, _: i32) {
+			self.log.borrow_mut().push(self.name);
+		}
+
+		fn on_text_input(&mut self, _: &str) -> bool {
+			self.log.borrow_mut().push(self.name);
+			self.handles
+		}
+
+		fn on_mouse_scroll(&mut self, _: Vec2) -> bool {
+			self.log.borrow_mut().push(self.name);
+			self.handles
+		}
+
+		fn on_custom_event(&mut self, _: &CustomEvent) -> bool {
+			self.log.borrow_mut().push(self.name);
+			self.handles
+		}
+	}
+
+	impl Layer for FilteredLayer {
+		fn event_filter(&self) -> EventCategory {
+			self.filter
+		}
+
+		fn wants_text_input(&self) -> bool {
+			self.text_input
+		}
+
+		fn wants_custom_event(&self, payload: TypeId) -> bool {
+			match self.payload {
+				Some(wanted) => wanted == payload,
+				None => true,
+			}
+		}
+	}
+
+	fn headless(frames: usize) -> Application {
+		headless_with_delta(frames, Duration::from_millis(10))
+	}
+
+	fn headless_with_delta(frames: usize, delta: Duration) -> Application {
+		let mut backend = HeadlessBackend::new().with_fixed_delta(delta);
+		backend.push_empty_frames(frames);
+		let window = window::Window::headless(WindowConfig::default(), backend);
+		Application::with_window(window).unwrap()
+	}
+
+	#[test]
+	fn start_runs_until_the_window_closes() {
+		let _lock = crate::test_lock();
+		let updates = Rc::new(Cell::new(0));
+		let mut app = headless(3);
+		app.push_layer(Box::new(CountingLayer {
+			updates: updates.clone(),
+			quit_after: None,
+		}));
+		assert_eq!(app.start().unwrap(), 0);
+		// The frame closing the window still runs its updates
+		assert_eq!(updates.get(), 4);
+	}
+
+	#[test]
+	fn start_returns_the_exit_code() {
+		let _lock = crate::test_lock();
+		let updates = Rc::new(Cell::new(0));
+		let mut app = headless(10);
+		let control = app.control();
+		app.push_layer(Box::new(CountingLayer {
+			updates: updates.clone(),
+			quit_after: Some((control, 2)),
+		}));
+		assert_eq!(app.start().unwrap(), 3);
+		assert_eq!(updates.get(), 2);
+	}
+
+	fn run_timed(mut app: Application) -> Vec<(u32, Float)> {
+		let frames = Rc::new(RefCell::new(Vec::new()));
+		app.push_layer(Box::new(TimingLayer {
+			fixed_updates: 0,
+			frames: frames.clone(),
+		}));
+		app.start().unwrap();
+		let frames = frames.borrow().clone();
+		frames
+	}
+
+	#[test]
+	fn runs_fixed_updates_at_the_fixed_rate() {
+		let _lock = crate::test_lock();
+		// 3/16 s per frame against fixed updates every 2/16 s
+		let mut app = headless_with_delta(3, Duration::from_micros(187_500));
+		app.set_fixed_update_rate(8.0);
+		let frames = run_timed(app);
+		assert_eq!(frames, [(1, 0.5), (2, 0.0), (1, 0.5), (2, 0.0)]);
+	}
+
+	#[test]
+	fn drops_the_time_the_fixed_updates_cannot_catch_up_on() {
+		let _lock = crate::test_lock();
+		// 9/16 s per frame would take 4.5 fixed updates
+		let mut app = headless_with_delta(3, Duration::from_micros(562_500));
+		app.set_fixed_update_rate(8.0);
+		app.set_max_fixed_steps(2);
+		let frames = run_timed(app);
+		assert_eq!(frames, [(2, 0.5), (2, 0.0), (2, 0.5), (2, 0.0)]);
+	}
+
+	/// Runs four frames of three fixed updates each, returning the updates
+	/// and fixed updates run in every frame
+	fn run_scripted<F>(script: F) -> Vec<(u32, u32)>
+	where
+		F: FnMut(usize, &AppControl) + 'static,
+	{
+		let mut app = headless_with_delta(3, Duration::from_millis(375));
+		app.set_fixed_update_rate(8.0);
+		let frames = Rc::new(RefCell::new(Vec::new()));
+		app.push_layer(Box::new(ScriptedLayer {
+			control: app.control(),
+			script: Box::new(script),
+			updates: 0,
+			fixed_updates: 0,
+			frames: frames.clone(),
+		}));
+		app.start().unwrap();
+		let frames = frames.borrow().clone();
+		frames
+	}
+
+	#[test]
+	fn pausing_stops_the_updates() {
+		let _lock = crate::test_lock();
+		let frames = run_scripted(|frame, control| {
+			if frame == 0 {
+				control.pause();
+			}
+		});
+		assert_eq!(frames, [(1, 3), (0, 0), (0, 0), (0, 0)]);
+	}
+
+	#[test]
+	fn steps_run_one_update_and_fixed_update_each() {
+		let _lock = crate::test_lock();
+		let frames = run_scripted(|frame, control| {
+			if frame == 0 {
+				control.pause();
+				control.step();
+				control.step();
+			}
+		});
+		assert_eq!(frames, [(1, 3), (1, 1), (1, 1), (0, 0)]);
+	}
+
+	#[test]
+	fn resuming_runs_the_updates_again() {
+		let _lock = crate::test_lock();
+		let frames = run_scripted(|frame, control| match frame {
+			0 => {
+				control.pause();
+				control.step();
+				control.step();
+			}
+			1 => control.resume(),
+			_ => (),
+		});
+		assert_eq!(frames, [(1, 3), (1, 1), (1, 3), (1, 3)]);
+	}
+
+	#[test]
+	fn dispatches_custom_events_to_the_layers_wanting_them() {
+		let _lock = crate::test_lock();
+		let log = Rc::new(RefCell::new(Vec::new()));
+		let mut app = headless(0);
+		app.push_layer(Box::new(FilteredLayer::new("bottom", &log)));
+		app.push_overlay(Box::new(FilteredLayer {
+			payload: Some(TypeId::of::<u32>()),
+			handles: true,
+			..FilteredLayer::new("top", &log)
+		}));
+
+		app.dispatch(&mut CustomEvent::new("not a number"));
+		assert_eq!(log.replace(Vec::new()), ["bottom"]);
+		// Handled by the top layer, so it doesn't reach the bottom one
+		app.dispatch(&mut CustomEvent::new(1u32));
+		assert_eq!(log.replace(Vec::new()), ["top"]);
+	}
+
+	#[test]
+	fn dispatches_events_to_the_layers_filtering_for_them() {
+		let _lock = crate::test_lock();
+		let log = Rc::new(RefCell::new(Vec::new()));
+		let mut app = headless(0);
+		app.push_layer(Box::new(FilteredLayer {
+			filter: EventCategory::WINDOW | EventCategory::TEXT,
+			..FilteredLayer::new("bottom", &log)
+		}));
+		app.push_overlay(Box::new(FilteredLayer {
+			filter: EventCategory::MOUSE,
+			text_input: true,
+			..FilteredLayer::new("top", &log)
+		}));
+
+		app.dispatch(&mut events::MouseScrolledEvent::new(Vec2::new(0.0, 1.0)));
+		assert_eq!(log.replace(Vec::new()), ["top"]);
+		app.dispatch(&mut events::WindowMovedEvent::new(10, 20));
+		assert_eq!(log.replace(Vec::new()), ["bottom"]);
+		// Text also needs the layer to want text input
+		app.dispatch(&mut events::TextInputEvent::new("text"));
+		assert!(log.replace(Vec::new()).is_empty());
+		app.dispatch(&mut CustomEvent::new(1u32));
+		assert!(log.replace(Vec::new()).is_empty());
+	}
+
+	#[test]
+	fn only_one_application_exists_at_a_time() {
+		let _lock = crate::test_lock();
+		let app = headless(0);
+		let window = window::Window::headless(WindowConfig::default(), HeadlessBackend::new());
+		let err = Application::with_window(window).err().unwrap();
+		assert_eq!(err.kind(), ErrorKind::AlreadyRunning);
+		drop(app);
+		let window = window::Window::headless(WindowConfig::default(), HeadlessBackend::new());
+		assert!(Application::with_window(window).is_ok());
+	}
+}
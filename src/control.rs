@@ -0,0 +1,276 @@
+use crate::vecs::Vec2;
+use crate::window::{self, CursorIcon, WindowConfig, WindowIcon, WindowId, WindowMode};
+use crate::Float;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// A handle for controlling the main loop of an `Application`.
+///
+/// Clones control the same application, so layers can keep a clone of
+/// `Application::control` around. Unlike an `EventBus` it can also be sent
+/// to other threads. Requests take effect from the next frame on.
+#[derive(Clone)]
+pub struct AppControl {
+	state: Arc<Mutex<ControlState>>,
+}
+
+struct ControlState {
+	exit_code: Option<i32>,
+	paused: bool,
+	steps: u32,
+	max_frame_rate: Option<Float>,
+	main_window: WindowId,
+	opened_windows: Vec<(WindowId, WindowConfig)>,
+	closed_windows: Vec<WindowId>,
+	window_commands: Vec<(WindowId, WindowCommand)>,
+}
+
+/// A change to a window, applied by the application at the start of a frame
+pub(crate) enum WindowCommand {
+	Vsync(bool),
+	Title(String),
+	Size(u32, u32),
+	Position(i32, i32),
+	Resizable(bool),
+	Mode(WindowMode),
+	MinSize(Option<(u32, u32)>),
+	MaxSize(Option<(u32, u32)>),
+	Icon(Option<WindowIcon>),
+	CursorIcon(CursorIcon),
+	CursorVisible(bool),
+	CursorGrabbed(bool),
+	RelativeMouse(bool),
+	CursorPosition(Vec2),
+}
+
+impl AppControl {
+	pub(crate) fn new(main_window: WindowId) -> AppControl {
+		AppControl {
+			state: Arc::new(Mutex::new(ControlState {
+				exit_code: None,
+				paused: false,
+				steps: 0,
+				max_frame_rate: None,
+				main_window,
+				opened_windows: Vec::new(),
+				closed_windows: Vec::new(),
+				window_commands: Vec::new(),
+			})),
+		}
+	}
+
+	fn state(&self) -> MutexGuard<'_, ControlState> {
+		self.state.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	/// Stops the application once the current frame is done, with exit code 0.
+	pub fn quit(&self) {
+		self.quit_with(0);
+	}
+
+	/// Stops the application once the current frame is done, making
+	/// `Application::start` return `code`. Only the first request counts.
+	pub fn quit_with(&self, code: i32) {
+		let mut state = self.state();
+		if state.exit_code.is_none() {
+			state.exit_code = Some(code);
+		}
+	}
+
+	pub fn is_quitting(&self) -> bool {
+		self.state().exit_code.is_some()
+	}
+
+	pub(crate) fn get_exit_code(&self) -> Option<i32> {
+		self.state().exit_code
+	}
+
+	/// Stops calling `on_update` and `on_fixed_update` until resumed. Events
+	/// are still dispatched and windows still rendered.
+	pub fn pause(&self) {
+		self.set_paused(true);
+	}
+
+	pub fn resume(&self) {
+		self.set_paused(false);
+	}
+
+	pub fn set_paused(&self, paused: bool) {
+		let mut state = self.state();
+		state.paused = paused;
+		state.steps = 0;
+	}
+
+	pub fn is_paused(&self) -> bool {
+		self.state().paused
+	}
+
+	/// While paused, runs the updates of a single frame, including exactly
+	/// one fixed update. Steps requested in the same frame are run one per
+	/// frame.
+	pub fn step(&self) {
+		let mut state = self.state();
+		if state.paused {
+			state.steps += 1;
+		}
+	}
+
+	/// Whether this frame's updates should run, using up a step if paused
+	pub(crate) fn take_step(&self) -> bool {
+		let mut state = self.state();
+		if !state.paused {
+			return true;
+		}
+		if state.steps > 0 {
+			state.steps -= 1;
+			return true;
+		}
+		false
+	}
+
+	/// Limits the frame rate by sleeping at the end of each frame, on top of
+	/// any limit set by vsync. `None`, the default, doesn't limit it.
+	pub fn set_max_frame_rate(&self, fps: Option<Float>) {
+		if let Some(fps) = fps {
+			assert!(fps > 0.0, "max frame rate must be positive");
+		}
+		self.state().max_frame_rate = fps;
+	}
+
+	pub fn get_max_frame_rate(&self) -> Option<Float> {
+		self.state().max_frame_rate
+	}
+
+	/// A handle for changing the main window
+	pub fn main_window(&self) -> WindowControl {
+		let id = self.state().main_window;
+		self.window(id)
+	}
+
+	/// A handle for changing the window `id`. Commands for windows that have
+	/// been closed are ignored.
+	pub fn window(&self, id: WindowId) -> WindowControl {
+		WindowControl {
+			id,
+			control: self.clone(),
+		}
+	}
+
+	/// Opens another window at the start of the next frame, see
+	/// `Application::open_window`. If it can't be created the error is
+	/// logged and the returned id never shows up.
+	pub fn open_window(&self, config: WindowConfig) -> WindowId {
+		let id = window::reserve_window_id();
+		self.state().opened_windows.push((id, config));
+		id
+	}
+
+	/// Closing the main window stops the application instead.
+	pub fn close_window(&self, id: WindowId) {
+		self.state().closed_windows.push(id);
+	}
+
+	pub(crate) fn take_opened_windows(&self) -> Vec<(WindowId, WindowConfig)> {
+		std::mem::take(&mut self.state().opened_windows)
+	}
+
+	pub(crate) fn take_window_commands(&self) -> Vec<(WindowId, WindowCommand)> {
+		std::mem::take(&mut self.state().window_commands)
+	}
+
+	pub(crate) fn take_closed_windows(&self) -> Vec<WindowId> {
+		std::mem::take(&mut self.state().closed_windows)
+	}
+}
+
+/// A handle for changing a window of a running application, see
+/// `AppControl::window`. Mirrors the setters of `Window`, failures are
+/// logged.
+#[derive(Clone)]
+pub struct WindowControl {
+	id: WindowId,
+	control: AppControl,
+}
+
+impl WindowControl {
+	pub fn get_id(&self) -> WindowId {
+		self.id
+	}
+
+	fn push(&self, command: WindowCommand) {
+		self.control.state().window_commands.push((self.id, command));
+	}
+
+	pub fn set_vsync(&self, vsync: bool) {
+		self.push(WindowCommand::Vsync(vsync));
+	}
+
+	pub fn set_title(&self, title: &str) {
+		self.push(WindowCommand::Title(title.to_string()));
+	}
+
+	pub fn set_size(&self, width: u32, height: u32) {
+		self.push(WindowCommand::Size(width, height));
+	}
+
+	pub fn set_position(&self, x: i32, y: i32) {
+		self.push(WindowCommand::Position(x, y));
+	}
+
+	pub fn set_resizable(&self, resizable: bool) {
+		self.push(WindowCommand::Resizable(resizable));
+	}
+
+	pub fn set_mode(&self, mode: WindowMode) {
+		self.push(WindowCommand::Mode(mode));
+	}
+
+	pub fn set_min_size(&self, size: Option<(u32, u32)>) {
+		self.push(WindowCommand::MinSize(size));
+	}
+
+	pub fn set_max_size(&self, size: Option<(u32, u32)>) {
+		self.push(WindowCommand::MaxSize(size));
+	}
+
+	pub fn set_icon(&self, icon: Option<WindowIcon>) {
+		self.push(WindowCommand::Icon(icon));
+	}
+
+	pub fn set_cursor_icon(&self, icon: CursorIcon) {
+		self.push(WindowCommand::CursorIcon(icon));
+	}
+
+	pub fn set_cursor_visible(&self, visible: bool) {
+		self.push(WindowCommand::CursorVisible(visible));
+	}
+
+	pub fn set_cursor_grabbed(&self, grabbed: bool) {
+		self.push(WindowCommand::CursorGrabbed(grabbed));
+	}
+
+	pub fn set_relative_mouse(&self, enabled: bool) {
+		self.push(WindowCommand::RelativeMouse(enabled));
+	}
+
+	pub fn set_cursor_position(&self, position: Vec2) {
+		self.push(WindowCommand::CursorPosition(position));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn resuming_drops_pending_steps() {
+		let control = AppControl::new(WindowId(0));
+		control.pause();
+		control.step();
+		control.step();
+		control.resume();
+		assert!(control.take_step());
+		// Pausing again would clear the steps by itself
+		control.state().paused = true;
+		assert!(!control.take_step());
+	}
+}
@@ -1,14 +1,140 @@
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ErrorKind {
+	/// An `Application` already exists
+	AlreadyRunning,
+	/// The window or its GL context could not be created
+	WindowCreation,
+	/// The GL context was lost and has to be recreated
+	ContextLost,
+	/// An operation on the GL context failed
+	Context,
+	/// Reading or writing a file failed
+	Io,
+	/// An asset could not be loaded
+	Asset,
+	/// A shader failed to compile or link
+	Shader,
+	/// Text, e.g. a config file, was malformed
+	Parse,
+	/// Anything not covered by the other kinds
+	Other,
+}
+
+impl ErrorKind {
+	fn description(self) -> &'static str {
+		use ErrorKind::*;
+		match self {
+			AlreadyRunning => "application already running",
+			WindowCreation => "failed to create window",
+			ContextLost => "GL context lost",
+			Context => "GL context error",
+			Io => "I/O error",
+			Asset => "failed to load asset",
+			Shader => "shader error",
+			Parse => "parse error",
+			Other => "error",
+		}
+	}
+}
+
+impl fmt::Display for ErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.description())
+	}
+}
+
+type Source = Box<dyn std::error::Error + Send + Sync + 'static>;
+
 #[derive(Debug)]
 pub struct Error {
-	
+	kind: ErrorKind,
+	message: Option<String>,
+	source: Option<Source>,
+}
+
+impl Error {
+	pub fn new(kind: ErrorKind) -> Error {
+		Error {
+			kind,
+			message: None,
+			source: None,
+		}
+	}
+
+	pub fn with_message<S: Into<String>>(kind: ErrorKind, message: S) -> Error {
+		Error {
+			kind,
+			message: Some(message.into()),
+			source: None,
+		}
+	}
+
+	pub fn with_source<E>(kind: ErrorKind, source: E) -> Error
+	where
+		E: std::error::Error + Send + Sync + 'static,
+	{
+		Error {
+			kind,
+			message: None,
+			source: Some(Box::new(source)),
+		}
+	}
+
+	pub fn kind(&self) -> ErrorKind {
+		self.kind
+	}
+
+	pub fn message(&self) -> Option<&str> {
+		self.message.as_deref()
+	}
+
+	/// Prefixes the message with the file the error happened on
+	pub(crate) fn in_file(mut self, path: &Path) -> Error {
+		self.message = Some(match self.message {
+			Some(message) => format!("{}: {}", path.display(), message),
+			None => path.display().to_string(),
+		});
+		self
+	}
 }
 
-impl std::fmt::Display for Error {
-	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::result::Result<(), std::fmt::Error> {
-		write!(f, "Rame error")
+impl From<ErrorKind> for Error {
+	fn from(kind: ErrorKind) -> Error {
+		Error::new(kind)
+	}
+}
+
+impl From<io::Error> for Error {
+	fn from(err: io::Error) -> Error {
+		// The message is kept so the reason shows up even where only
+		// `Display` is logged
+		Error {
+			kind: ErrorKind::Io,
+			message: Some(err.to_string()),
+			source: Some(Box::new(err)),
+		}
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.kind)?;
+		if let Some(message) = &self.message {
+			write!(f, ": {}", message)?;
+		}
+		Ok(())
 	}
 }
 
 impl std::error::Error for Error {
-	
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match &self.source {
+			Some(source) => Some(&**source),
+			None => None,
+		}
+	}
 }
@@ -1,10 +1,17 @@
-use super::{Event, EventBox, EventListener};
+use super::{Event, EventBox, EventCategory, EventListener, EventType};
+use crate::time::Timestep;
+use crate::Float;
+use std::fmt;
 
-pub struct AppUpdateEvent;
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AppUpdateEvent {
+	time: Timestep,
+}
 
 impl AppUpdateEvent {
-	pub fn new() -> EventBox {
-		Box::new(AppUpdateEvent)
+	pub fn new(time: Timestep) -> EventBox {
+		Box::new(AppUpdateEvent { time })
 	}
 }
 
@@ -12,16 +19,65 @@ impl Event for AppUpdateEvent {
 	fn is_handled(&self) -> bool {
 		false
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
-		listener.on_update();
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_update(self.time);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::AppUpdate
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::APPLICATION
+	}
+}
+
+impl fmt::Display for AppUpdateEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "AppUpdate(delta: {}s, elapsed: {}s)", self.time.delta, self.time.elapsed)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AppFixedUpdateEvent {
+	time: Timestep,
+}
+
+impl AppFixedUpdateEvent {
+	pub fn new(time: Timestep) -> EventBox {
+		Box::new(AppFixedUpdateEvent { time })
+	}
+}
+
+impl Event for AppFixedUpdateEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_fixed_update(self.time);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::AppFixedUpdate
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::APPLICATION
+	}
+}
+
+impl fmt::Display for AppFixedUpdateEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "AppFixedUpdate(delta: {}s, elapsed: {}s)", self.time.delta, self.time.elapsed)
 	}
 }
 
-pub struct AppRenderEvent;
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AppRenderEvent {
+	alpha: Float,
+}
 
 impl AppRenderEvent {
-	pub fn new() -> EventBox {
-		Box::new(AppRenderEvent)
+	pub fn new(alpha: Float) -> EventBox {
+		Box::new(AppRenderEvent { alpha })
 	}
 }
 
@@ -29,7 +85,19 @@ impl Event for AppRenderEvent {
 	fn is_handled(&self) -> bool {
 		false
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
-		listener.on_render();
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_render(self.alpha);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::AppRender
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::APPLICATION
+	}
+}
+
+impl fmt::Display for AppRenderEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "AppRender(alpha: {})", self.alpha)
 	}
 }
@@ -0,0 +1,87 @@
+use super::Event;
+use super::EventBox;
+use super::EventCategory;
+use super::EventListener;
+use super::EventType;
+use std::any::{self, Any, TypeId};
+use std::fmt;
+
+/// An event of a type defined outside of rame, usually published through
+/// an `EventBus`.
+///
+/// Listeners receive it through `EventListener::on_custom_event` and pick
+/// out the types they care about with `handle` or `downcast_ref`. Layers
+/// can also skip other types entirely, see `Layer::wants_custom_event`.
+pub struct CustomEvent {
+	handled: bool,
+	type_id: TypeId,
+	type_name: &'static str,
+	payload: Box<dyn Any>,
+}
+
+impl CustomEvent {
+	pub fn new<T: Any>(payload: T) -> EventBox {
+		Box::new(Self {
+			handled: false,
+			type_id: TypeId::of::<T>(),
+			type_name: any::type_name::<T>(),
+			payload: Box::new(payload),
+		})
+	}
+
+	/// The name of the payload's type, for diagnostics
+	pub fn type_name(&self) -> &'static str {
+		self.type_name
+	}
+
+	pub fn is<T: Any>(&self) -> bool {
+		self.payload.is::<T>()
+	}
+
+	pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+		self.payload.downcast_ref()
+	}
+
+	/// Calls `handler` if the payload is a `T`, returning whether the event
+	/// was handled.
+	pub fn handle<T: Any, F: FnOnce(&T) -> bool>(&self, handler: F) -> bool {
+		match self.downcast_ref() {
+			Some(payload) => handler(payload),
+			None => false,
+		}
+	}
+}
+
+impl Event for CustomEvent {
+	fn is_handled(&self) -> bool {
+		self.handled
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		debug_assert!(!self.handled);
+		self.handled = listener.on_custom_event(self);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::Custom
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::CUSTOM
+	}
+	fn payload_type(&self) -> Option<TypeId> {
+		Some(self.type_id)
+	}
+}
+
+impl fmt::Debug for CustomEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("CustomEvent")
+			.field("handled", &self.handled)
+			.field("type_name", &self.type_name)
+			.finish()
+	}
+}
+
+impl fmt::Display for CustomEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Custom({})", self.type_name)
+	}
+}
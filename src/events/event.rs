@@ -1,6 +1,196 @@
 use super::EventListener;
+use std::any::TypeId;
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitOrAssign};
 
-pub trait Event {
+/// Something that happened, to be dispatched to event listeners.
+///
+/// Events print their name and fields with `Display`, and all their state
+/// with `Debug`. With the `serde` feature enabled every built-in event
+/// except `CustomEvent` can also be serialized.
+pub trait Event: fmt::Debug + fmt::Display {
 	fn is_handled(&self) -> bool;
-	fn dispatch(&mut self, listener: &mut EventListener);
+	fn dispatch(&mut self, listener: &mut dyn EventListener);
+	fn event_type(&self) -> EventType;
+	fn categories(&self) -> EventCategory;
+	fn name(&self) -> &'static str {
+		self.event_type().name()
+	}
+	/// Whether this event makes a directly preceding event of the same type
+	/// redundant, so a coalescing `EventQueue` may drop the earlier one.
+	fn is_coalescable(&self) -> bool {
+		false
+	}
+	fn is_in_category(&self, category: EventCategory) -> bool {
+		self.categories().intersects(category)
+	}
+	/// The type of the payload of a `CustomEvent`, `None` for built-in events
+	fn payload_type(&self) -> Option<TypeId> {
+		None
+	}
+	/// The fields of the event as written by an `EventRecorder`, or `None`
+	/// if the event can't be recorded.
+	fn record(&self) -> Option<String> {
+		None
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EventType {
+	AppUpdate,
+	AppFixedUpdate,
+	AppRender,
+	WindowClosed,
+	WindowResized,
+	FramebufferResized,
+	WindowFocus,
+	WindowMoved,
+	WindowMinimized,
+	DpiChanged,
+	CursorEntered,
+	CursorLeft,
+	FileDropped,
+	FileHovered,
+	FileHoverCancelled,
+	KeyPressed,
+	KeyReleased,
+	CharWritten,
+	TextInput,
+	TextComposition,
+	MousePressed,
+	MouseReleased,
+	MouseMoved,
+	MouseMotion,
+	MouseScrolled,
+	GamepadConnected,
+	GamepadDisconnected,
+	GamepadPressed,
+	GamepadReleased,
+	GamepadAxisMoved,
+	Custom,
+}
+
+impl EventType {
+	pub fn name(self) -> &'static str {
+		match self {
+			EventType::AppUpdate => "AppUpdate",
+			EventType::AppFixedUpdate => "AppFixedUpdate",
+			EventType::AppRender => "AppRender",
+			EventType::WindowClosed => "WindowClosed",
+			EventType::WindowResized => "WindowResized",
+			EventType::FramebufferResized => "FramebufferResized",
+			EventType::WindowFocus => "WindowFocus",
+			EventType::WindowMoved => "WindowMoved",
+			EventType::WindowMinimized => "WindowMinimized",
+			EventType::DpiChanged => "DpiChanged",
+			EventType::CursorEntered => "CursorEntered",
+			EventType::CursorLeft => "CursorLeft",
+			EventType::FileDropped => "FileDropped",
+			EventType::FileHovered => "FileHovered",
+			EventType::FileHoverCancelled => "FileHoverCancelled",
+			EventType::KeyPressed => "KeyPressed",
+			EventType::KeyReleased => "KeyReleased",
+			EventType::CharWritten => "CharWritten",
+			EventType::TextInput => "TextInput",
+			EventType::TextComposition => "TextComposition",
+			EventType::MousePressed => "MousePressed",
+			EventType::MouseReleased => "MouseReleased",
+			EventType::MouseMoved => "MouseMoved",
+			EventType::MouseMotion => "MouseMotion",
+			EventType::MouseScrolled => "MouseScrolled",
+			EventType::GamepadConnected => "GamepadConnected",
+			EventType::GamepadDisconnected => "GamepadDisconnected",
+			EventType::GamepadPressed => "GamepadPressed",
+			EventType::GamepadReleased => "GamepadReleased",
+			EventType::GamepadAxisMoved => "GamepadAxisMoved",
+			EventType::Custom => "Custom",
+		}
+	}
+}
+
+impl fmt::Display for EventType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+/// A set of event categories. An event can belong to several categories,
+/// e.g. a key press is both `INPUT` and `KEYBOARD`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventCategory(u16);
+
+impl EventCategory {
+	pub const NONE: EventCategory = EventCategory(0);
+	pub const APPLICATION: EventCategory = EventCategory(1);
+	pub const WINDOW: EventCategory = EventCategory(1 << 1);
+	pub const INPUT: EventCategory = EventCategory(1 << 2);
+	pub const KEYBOARD: EventCategory = EventCategory(1 << 3);
+	pub const TEXT: EventCategory = EventCategory(1 << 4);
+	pub const MOUSE: EventCategory = EventCategory(1 << 5);
+	pub const MOUSE_BUTTON: EventCategory = EventCategory(1 << 6);
+	pub const GAMEPAD: EventCategory = EventCategory(1 << 7);
+	pub const CUSTOM: EventCategory = EventCategory(1 << 8);
+	pub const ALL: EventCategory = EventCategory((1 << 9) - 1);
+
+	pub fn bits(self) -> u16 {
+		self.0
+	}
+	pub fn contains(self, other: EventCategory) -> bool {
+		self.0 & other.0 == other.0
+	}
+	pub fn intersects(self, other: EventCategory) -> bool {
+		self.0 & other.0 != 0
+	}
+}
+
+impl BitOr for EventCategory {
+	type Output = EventCategory;
+	fn bitor(self, other: EventCategory) -> EventCategory {
+		EventCategory(self.0 | other.0)
+	}
+}
+
+impl BitOrAssign for EventCategory {
+	fn bitor_assign(&mut self, other: EventCategory) {
+		self.0 |= other.0;
+	}
+}
+
+impl BitAnd for EventCategory {
+	type Output = EventCategory;
+	fn bitand(self, other: EventCategory) -> EventCategory {
+		EventCategory(self.0 & other.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::events::{CustomEvent, KeyPressedEvent, MouseScrolledEvent};
+	use crate::input::Button;
+	use crate::vecs::Vec2;
+
+	#[test]
+	fn combines_categories() {
+		let input = EventCategory::INPUT | EventCategory::KEYBOARD;
+		assert!(input.contains(EventCategory::KEYBOARD));
+		assert!(!input.contains(EventCategory::KEYBOARD | EventCategory::MOUSE));
+		assert!(input.intersects(EventCategory::KEYBOARD | EventCategory::MOUSE));
+		assert_eq!(input & EventCategory::MOUSE, EventCategory::NONE);
+		assert!(EventCategory::ALL.contains(input | EventCategory::CUSTOM));
+	}
+
+	#[test]
+	fn puts_events_in_categories() {
+		let key = KeyPressedEvent::new(Button::W, false);
+		assert_eq!(key.categories(), EventCategory::INPUT | EventCategory::KEYBOARD);
+		assert!(key.is_in_category(EventCategory::KEYBOARD | EventCategory::MOUSE));
+		let scroll = MouseScrolledEvent::new(Vec2::new(0.0, 1.0));
+		assert!(scroll.is_in_category(EventCategory::MOUSE));
+		assert!(!scroll.is_in_category(EventCategory::MOUSE_BUTTON));
+		assert!(!CustomEvent::new(1u32).is_in_category(EventCategory::INPUT));
+		assert!(!key.is_in_category(EventCategory::NONE));
+	}
 }
@@ -0,0 +1,155 @@
+use super::event_sender::SentEvent;
+use super::{CustomEvent, Delay, EventBox, EventQueue, EventSender, Priority};
+use crate::Float;
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc;
+
+/// A handle for publishing events to every layer of an `Application`.
+///
+/// Clones publish to the same application, so layers can keep a clone of
+/// `Application::event_bus` around. Events published during a frame are
+/// dispatched at the start of the next one, after the window's events.
+/// Other threads can publish through an `EventSender`.
+#[derive(Clone)]
+pub struct EventBus {
+	queue: Rc<RefCell<EventQueue>>,
+	sender: mpsc::Sender<SentEvent>,
+	receiver: Rc<mpsc::Receiver<SentEvent>>,
+}
+
+impl EventBus {
+	pub fn new() -> EventBus {
+		let (sender, receiver) = mpsc::channel();
+		EventBus {
+			queue: Rc::new(RefCell::new(EventQueue::new())),
+			sender,
+			receiver: Rc::new(receiver),
+		}
+	}
+
+	/// Publishes a value of any type, wrapped in a `CustomEvent`.
+	pub fn publish<T: Any>(&self, event: T) {
+		self.publish_event(CustomEvent::new(event));
+	}
+
+	/// Publishes an already boxed event, which may also be a built-in one.
+	pub fn publish_event(&self, event: EventBox) {
+		self.queue.borrow_mut().push(event);
+	}
+
+	pub fn publish_with_priority(&self, event: EventBox, priority: Priority) {
+		self.queue.borrow_mut().push_with_priority(event, priority);
+	}
+
+	/// Publishes a value of any type once `delay` has passed.
+	pub fn publish_delayed<T: Any>(&self, event: T, delay: Delay) {
+		self.publish_event_delayed(CustomEvent::new(event), delay, Priority::Normal);
+	}
+
+	pub fn publish_event_delayed(&self, event: EventBox, delay: Delay, priority: Priority) {
+		self.queue.borrow_mut().push_delayed(event, delay, priority);
+	}
+
+	/// A handle for publishing to this bus from other threads
+	pub fn sender(&self) -> EventSender {
+		EventSender::new(self.sender.clone())
+	}
+
+	/// Starts a new frame lasting `delta` seconds, taking all events that
+	/// are due.
+	pub(crate) fn take(&self, delta: Float) -> EventQueue {
+		let mut queue = self.queue.borrow_mut();
+		for sent in self.receiver.try_iter() {
+			let event = (sent.make_event)();
+			match sent.delay {
+				Some(delay) => queue.push_delayed(event, delay, sent.priority),
+				None => queue.push_with_priority(event, sent.priority),
+			}
+		}
+		queue.advance(delta);
+		queue.take_ready()
+	}
+}
+
+impl Default for EventBus {
+	fn default() -> EventBus {
+		EventBus::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::events::{EventListener, EventType, WindowClosedEvent};
+	use std::any::TypeId;
+	use std::thread;
+
+	/// Collects the payloads of the custom events it receives
+	#[derive(Default)]
+	struct Numbers(Vec<u32>);
+
+	impl EventListener for Numbers {
+		fn on_custom_event(&mut self, event: &CustomEvent) -> bool {
+			event.handle(|number: &u32| {
+				self.0.push(*number);
+				true
+			})
+		}
+	}
+
+	fn numbers(queue: EventQueue) -> Vec<u32> {
+		let mut listener = Numbers::default();
+		for mut event in queue {
+			event.dispatch(&mut listener);
+		}
+		listener.0
+	}
+
+	#[test]
+	fn publishes_events_of_any_type() {
+		let bus = EventBus::new();
+		bus.publish(1u32);
+		bus.publish("not a number");
+		bus.publish_event(WindowClosedEvent::new());
+		bus.clone().publish_with_priority(CustomEvent::new(2u32), Priority::High);
+
+		let events: Vec<EventBox> = bus.take(0.0).collect();
+		let types: Vec<EventType> = events.iter().map(|event| event.event_type()).collect();
+		assert_eq!(types, [EventType::Custom, EventType::Custom, EventType::Custom, EventType::WindowClosed]);
+		let payloads: Vec<Option<TypeId>> = events.iter().map(|event| event.payload_type()).collect();
+		let number = Some(TypeId::of::<u32>());
+		assert_eq!(payloads, [number, number, Some(TypeId::of::<&str>()), None]);
+
+		bus.publish(1u32);
+		bus.publish_with_priority(CustomEvent::new(2u32), Priority::High);
+		assert_eq!(numbers(bus.take(0.0)), [2, 1]);
+		assert!(bus.take(0.0).is_empty());
+	}
+
+	#[test]
+	fn receives_events_from_other_threads() {
+		let bus = EventBus::new();
+		let sender = bus.sender();
+		thread::spawn(move || {
+			sender.send(1u32).unwrap();
+			sender.send_delayed(2u32, Delay::Frames(1)).unwrap();
+		})
+		.join()
+		.unwrap();
+		assert_eq!(numbers(bus.take(0.0)), [1]);
+		assert_eq!(numbers(bus.take(0.0)), [2]);
+	}
+
+	#[test]
+	fn delays_count_from_the_frame_after_publishing() {
+		let bus = EventBus::new();
+		bus.publish_delayed(1u32, Delay::Seconds(0.5));
+		bus.sender().send_delayed(2u32, Delay::Seconds(0.5)).unwrap();
+		// Published during the frame before the first one taken
+		assert!(bus.take(0.25).is_empty());
+		assert!(bus.take(0.25).is_empty());
+		assert_eq!(bus.take(0.25).len(), 2);
+	}
+}
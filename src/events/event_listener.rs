@@ -1,40 +1,108 @@
-use crate::input::Button;
+use super::CustomEvent;
+use crate::input::{Button, GamepadAxis, GamepadButton, GamepadId, Key};
+use crate::time::Timestep;
+use crate::Float;
 use crate::vecs::Vec2;
+use std::path::Path;
 
 pub trait EventListener: AsEventListener {
-	fn on_update(&mut self) {}
-	fn on_render(&mut self) {}
+	fn on_update(&mut self, _time: Timestep) {}
+	/// Called at a fixed rate, independent of the frame rate. `time.delta` is
+	/// always the same.
+	fn on_fixed_update(&mut self, _time: Timestep) {}
+	/// `alpha` is how far, from 0 to 1, the current frame is between the
+	/// previous and the next fixed update.
+	fn on_render(&mut self, _alpha: Float) {}
 	fn on_window_closed(&mut self) {}
-	fn on_window_resize(&mut self, _width: u32, _height: u32) {}
-	fn on_key_press(&mut self, _button: Button, _repeat: bool) -> bool {
+	/// `width` and `height` are in logical pixels, times `scale_factor` for
+	/// physical pixels.
+	fn on_window_resize(&mut self, _width: u32, _height: u32, _scale_factor: Float) {}
+	/// `width` and `height` are in physical pixels. Called with the window's
+	/// context current and its viewport already covering the new size, but
+	/// anything else sized to the framebuffer, like render targets, has to
+	/// be resized here.
+	fn on_framebuffer_resize(&mut self, _width: u32, _height: u32) {}
+	fn on_window_focus(&mut self, _focused: bool) {}
+	/// `x` and `y` are the position of the window on the desktop
+	fn on_window_move(&mut self, _x: i32, _y: i32) {}
+	/// Called with `false` when the window is restored. Only called on
+	/// Windows, see `WindowMinimizedEvent`.
+	fn on_window_minimize(&mut self, _minimized: bool) {}
+	/// `scale_factor` is the number of physical pixels per logical pixel
+	fn on_dpi_change(&mut self, _scale_factor: Float) {}
+	fn on_cursor_enter(&mut self) {}
+	fn on_cursor_leave(&mut self) {}
+	fn on_file_drop(&mut self, _path: &Path) -> bool {
 		false
 	}
-	fn on_key_release(&mut self, _button: Button) -> bool {
+	/// Called for every file dragged over the window
+	fn on_file_hover(&mut self, _path: &Path) -> bool {
+		false
+	}
+	fn on_file_hover_cancel(&mut self) {}
+	fn on_key_press(&mut self, _key: Key, _repeat: bool) -> bool {
+		false
+	}
+	fn on_key_release(&mut self, _key: Key) -> bool {
 		false
 	}
 	fn on_char_written(&mut self, _which: char) -> bool {
 		false
 	}
+	/// Text typed by the user, possibly the result of an IME composition.
+	/// Only called on layers that want text input.
+	fn on_text_input(&mut self, _text: &str) -> bool {
+		false
+	}
+	/// The text currently being composed with an IME, not yet committed.
+	/// `cursor` is a byte offset into `text`. Only called on layers that
+	/// want text input, and only for backends that report composition,
+	/// which `GlutinBackend` doesn't. It only delivers committed text.
+	fn on_text_composition(&mut self, _text: &str, _cursor: Option<usize>) -> bool {
+		false
+	}
 	fn on_mouse_press(&mut self, _button: Button) -> bool {
 		false
 	}
 	fn on_mouse_release(&mut self, _button: Button) -> bool {
 		false
 	}
-	fn on_mouse_move(&mut self, _position: Vec2) -> bool {
+	/// `position` is in logical pixels from the top left of the window,
+	/// times `scale_factor` for physical pixels.
+	fn on_mouse_move(&mut self, _position: Vec2, _scale_factor: Float) -> bool {
+		false
+	}
+	/// `delta` is in device units rather than pixels, see `MouseMotionEvent`
+	fn on_mouse_motion(&mut self, _delta: Vec2) -> bool {
 		false
 	}
 	fn on_mouse_scroll(&mut self, _delta: Vec2) -> bool {
 		false
 	}
+	/// Called for events of types defined outside of rame, see `EventBus`
+	fn on_custom_event(&mut self, _event: &CustomEvent) -> bool {
+		false
+	}
+	fn on_gamepad_connected(&mut self, _id: GamepadId) {}
+	fn on_gamepad_disconnected(&mut self, _id: GamepadId) {}
+	fn on_gamepad_press(&mut self, _id: GamepadId, _button: GamepadButton) -> bool {
+		false
+	}
+	fn on_gamepad_release(&mut self, _id: GamepadId, _button: GamepadButton) -> bool {
+		false
+	}
+	/// `value` is the raw value reported by the device, without any deadzone
+	fn on_gamepad_axis(&mut self, _id: GamepadId, _axis: GamepadAxis, _value: Float) -> bool {
+		false
+	}
 }
 
 pub trait AsEventListener {
-	fn as_event_listener(&mut self) -> &mut EventListener;
+	fn as_event_listener(&mut self) -> &mut dyn EventListener;
 }
 
 impl<T: EventListener> AsEventListener for T {
-	fn as_event_listener(&mut self) -> &mut EventListener {
+	fn as_event_listener(&mut self) -> &mut dyn EventListener {
 		self
 	}
 }
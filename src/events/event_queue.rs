@@ -1,20 +1,224 @@
 use super::EventBox;
+use crate::Float;
 use std::collections::VecDeque;
 
+/// The order in which queued events are taken out. Events of the same
+/// priority are taken out in the order they were pushed.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Priority {
+	High,
+	Normal,
+	Low,
+}
+
+/// How long to hold back a delayed event
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Delay {
+	/// Counted from the frame an undelayed event would arrive in, so time
+	/// that passed before the event was pushed doesn't count
+	Seconds(Float),
+	/// The number of frames the event arrives after an undelayed one would
+	Frames(u32),
+}
+
+struct DelayedEvent {
+	event: EventBox,
+	priority: Priority,
+	remaining: Delay,
+	// Whether `advance` has been called since the event was pushed
+	counting: bool,
+}
+
 pub struct EventQueue {
-	queue: VecDeque<EventBox>,
+	queues: [VecDeque<EventBox>; 3],
+	delayed: Vec<DelayedEvent>,
+	coalescing: bool,
 }
 
 impl EventQueue {
 	pub fn new() -> EventQueue {
 		EventQueue {
-			queue: VecDeque::new(),
+			queues: [VecDeque::new(), VecDeque::new(), VecDeque::new()],
+			delayed: Vec::new(),
+			coalescing: false,
 		}
 	}
+
+	/// When enabled, pushing an event that `Event::is_coalescable` replaces
+	/// the last queued event of the same priority if it is of the same type,
+	/// e.g. only the last of several mouse moves in a row is kept.
+	pub fn set_coalescing(&mut self, coalescing: bool) {
+		self.coalescing = coalescing;
+	}
+
+	pub fn is_coalescing(&self) -> bool {
+		self.coalescing
+	}
+
 	pub fn push(&mut self, value: EventBox) {
-		self.queue.push_back(value);
+		self.push_with_priority(value, Priority::Normal);
+	}
+
+	pub fn push_with_priority(&mut self, value: EventBox, priority: Priority) {
+		let queue = &mut self.queues[priority as usize];
+		if self.coalescing && value.is_coalescable() {
+			if let Some(last) = queue.back_mut() {
+				if last.event_type() == value.event_type() {
+					*last = value;
+					return;
+				}
+			}
+		}
+		queue.push_back(value);
+	}
+
+	/// Holds `value` back until `delay` has passed, see `advance`.
+	pub fn push_delayed(&mut self, value: EventBox, delay: Delay, priority: Priority) {
+		self.delayed.push(DelayedEvent {
+			event: value,
+			priority,
+			remaining: delay,
+			counting: false,
+		});
+	}
+
+	/// Counts down the delayed events by one frame lasting `delta` seconds,
+	/// queueing the ones that are due.
+	pub fn advance(&mut self, delta: Float) {
+		let mut due = Vec::new();
+		let mut i = 0;
+		while i < self.delayed.len() {
+			let delayed = &mut self.delayed[i];
+			let counting = std::mem::replace(&mut delayed.counting, true);
+			let is_due = match &mut delayed.remaining {
+				Delay::Seconds(seconds) => {
+					if counting {
+						*seconds -= delta;
+					}
+					*seconds <= 0.0
+				}
+				Delay::Frames(0) => true,
+				Delay::Frames(frames) => {
+					*frames -= 1;
+					false
+				}
+			};
+			if is_due {
+				// Keeps the order in which delayed events were pushed
+				due.push(self.delayed.remove(i));
+			} else {
+				i += 1;
+			}
+		}
+		for delayed in due {
+			self.push_with_priority(delayed.event, delayed.priority);
+		}
+	}
+
+	/// Moves all events that aren't delayed into a new queue.
+	pub fn take_ready(&mut self) -> EventQueue {
+		let mut ready = EventQueue::new();
+		for (queue, taken) in self.queues.iter_mut().zip(ready.queues.iter_mut()) {
+			std::mem::swap(queue, taken);
+		}
+		ready
+	}
+
+	/// The number of events ready to be taken out, not counting delayed ones
+	pub fn len(&self) -> usize {
+		self.queues.iter().map(VecDeque::len).sum()
 	}
-	pub fn next(&mut self) -> Option<EventBox> {
-		self.queue.pop_front()
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+impl Default for EventQueue {
+	fn default() -> EventQueue {
+		EventQueue::new()
+	}
+}
+
+impl Iterator for EventQueue {
+	type Item = EventBox;
+
+	fn next(&mut self) -> Option<EventBox> {
+		self.queues.iter_mut().find_map(VecDeque::pop_front)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::events::{EventType, MouseMovedEvent, WindowClosedEvent};
+	use crate::vecs::Vec2;
+
+	fn mouse_moved(x: Float) -> EventBox {
+		MouseMovedEvent::new(Vec2::new(x, 0.0), 1.0)
+	}
+
+	fn types(queue: EventQueue) -> Vec<EventType> {
+		queue.map(|event| event.event_type()).collect()
+	}
+
+	#[test]
+	fn takes_out_events_by_priority() {
+		let mut queue = EventQueue::new();
+		queue.push_with_priority(mouse_moved(0.0), Priority::Low);
+		queue.push(WindowClosedEvent::new());
+		queue.push_with_priority(mouse_moved(1.0), Priority::High);
+		assert_eq!(
+			types(queue),
+			[EventType::MouseMoved, EventType::WindowClosed, EventType::MouseMoved]
+		);
+	}
+
+	#[test]
+	fn delays_by_frames() {
+		let mut queue = EventQueue::new();
+		queue.push_delayed(WindowClosedEvent::new(), Delay::Frames(0), Priority::Normal);
+		queue.push_delayed(mouse_moved(0.0), Delay::Frames(2), Priority::Normal);
+		queue.advance(0.0);
+		assert_eq!(types(queue.take_ready()), [EventType::WindowClosed]);
+		queue.advance(0.0);
+		assert!(queue.take_ready().is_empty());
+		queue.advance(0.0);
+		assert_eq!(types(queue.take_ready()), [EventType::MouseMoved]);
+	}
+
+	#[test]
+	fn delays_by_seconds() {
+		let mut queue = EventQueue::new();
+		queue.push_delayed(WindowClosedEvent::new(), Delay::Seconds(0.5), Priority::Normal);
+		queue.push_delayed(mouse_moved(0.0), Delay::Seconds(0.0), Priority::Normal);
+		// The frame the events were pushed in doesn't count
+		queue.advance(0.25);
+		assert_eq!(types(queue.take_ready()), [EventType::MouseMoved]);
+		queue.advance(0.25);
+		assert!(queue.is_empty());
+		queue.advance(0.25);
+		assert_eq!(types(queue.take_ready()), [EventType::WindowClosed]);
+	}
+
+	#[test]
+	fn coalesces_runs_of_the_same_event() {
+		let mut queue = EventQueue::new();
+		queue.set_coalescing(true);
+		queue.push(mouse_moved(0.0));
+		queue.push(mouse_moved(1.0));
+		queue.push(WindowClosedEvent::new());
+		queue.push(mouse_moved(2.0));
+		queue.push(mouse_moved(3.0));
+		let fields: Vec<_> = queue.map(|event| event.record()).collect();
+		assert_eq!(fields, [Some("1 0 1".to_string()), Some(String::new()), Some("3 0 1".to_string())]);
+	}
+
+	#[test]
+	fn keeps_every_event_without_coalescing() {
+		let mut queue = EventQueue::new();
+		queue.push(mouse_moved(0.0));
+		queue.push(mouse_moved(1.0));
+		assert_eq!(queue.len(), 2);
 	}
 }
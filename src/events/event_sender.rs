@@ -0,0 +1,58 @@
+use super::{CustomEvent, Delay, EventBox, Priority};
+use crate::error::{Error, ErrorKind};
+use std::any::Any;
+use std::sync::mpsc;
+
+/// Creates the event on the receiving thread, as events themselves can't
+/// be sent between threads.
+type EventFactory = Box<dyn FnOnce() -> EventBox + Send>;
+
+pub(crate) struct SentEvent {
+	pub make_event: EventFactory,
+	pub priority: Priority,
+	pub delay: Option<Delay>,
+}
+
+/// A handle for publishing events to an `Application` from other threads,
+/// e.g. an asset loader or a network thread. Created by
+/// `EventBus::sender`.
+///
+/// Sent events are dispatched at the start of the next frame, like events
+/// published through the `EventBus`.
+#[derive(Clone)]
+pub struct EventSender {
+	sender: mpsc::Sender<SentEvent>,
+}
+
+impl EventSender {
+	pub(crate) fn new(sender: mpsc::Sender<SentEvent>) -> EventSender {
+		EventSender { sender }
+	}
+
+	/// Sends a value of any type, wrapped in a `CustomEvent`.
+	pub fn send<T: Any + Send>(&self, event: T) -> Result<(), Error> {
+		self.send_with(move || CustomEvent::new(event), Priority::Normal, None)
+	}
+
+	pub fn send_delayed<T: Any + Send>(&self, event: T, delay: Delay) -> Result<(), Error> {
+		self.send_with(move || CustomEvent::new(event), Priority::Normal, Some(delay))
+	}
+
+	/// Sends an event created by `make_event` on the application's thread,
+	/// which may also be a built-in one.
+	///
+	/// Fails if the application has been dropped.
+	pub fn send_with<F>(&self, make_event: F, priority: Priority, delay: Option<Delay>) -> Result<(), Error>
+	where
+		F: FnOnce() -> EventBox + Send + 'static,
+	{
+		let event = SentEvent {
+			make_event: Box::new(make_event),
+			priority,
+			delay,
+		};
+		self.sender
+			.send(event)
+			.map_err(|_| Error::with_message(ErrorKind::Other, "the application is no longer running"))
+	}
+}
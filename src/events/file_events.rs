@@ -0,0 +1,129 @@
+use super::recording;
+use super::{Event, EventBox, EventCategory, EventListener, EventType};
+use std::fmt;
+use std::path::PathBuf;
+
+// Files are dragged onto the window from outside of the application, so
+// these are window events. Unlike other window events, a drop can be
+// handled by a single layer.
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileDroppedEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
+	handled: bool,
+	path: PathBuf,
+}
+
+impl FileDroppedEvent {
+	pub fn new<P: Into<PathBuf>>(path: P) -> EventBox {
+		Box::new(Self {
+			handled: false,
+			path: path.into(),
+		})
+	}
+}
+
+impl Event for FileDroppedEvent {
+	fn is_handled(&self) -> bool {
+		self.handled
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		debug_assert!(!self.handled);
+		self.handled = listener.on_file_drop(&self.path);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::FileDropped
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW
+	}
+	fn record(&self) -> Option<String> {
+		Some(recording::escape(&self.path.to_string_lossy()))
+	}
+}
+
+impl fmt::Display for FileDroppedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "FileDropped({})", self.path.display())
+	}
+}
+
+/// Sent when a file is dragged over the window, once for every file
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileHoveredEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
+	handled: bool,
+	path: PathBuf,
+}
+
+impl FileHoveredEvent {
+	pub fn new<P: Into<PathBuf>>(path: P) -> EventBox {
+		Box::new(Self {
+			handled: false,
+			path: path.into(),
+		})
+	}
+}
+
+impl Event for FileHoveredEvent {
+	fn is_handled(&self) -> bool {
+		self.handled
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		debug_assert!(!self.handled);
+		self.handled = listener.on_file_hover(&self.path);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::FileHovered
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW
+	}
+	fn record(&self) -> Option<String> {
+		Some(recording::escape(&self.path.to_string_lossy()))
+	}
+}
+
+impl fmt::Display for FileHoveredEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "FileHovered({})", self.path.display())
+	}
+}
+
+/// Sent when hovered files are dragged away from the window without being
+/// dropped. Always propagates to every layer.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileHoverCancelledEvent;
+
+impl FileHoverCancelledEvent {
+	pub fn new() -> EventBox {
+		Box::new(FileHoverCancelledEvent)
+	}
+}
+
+impl Event for FileHoverCancelledEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_file_hover_cancel();
+	}
+	fn event_type(&self) -> EventType {
+		EventType::FileHoverCancelled
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW
+	}
+	fn record(&self) -> Option<String> {
+		Some(String::new())
+	}
+}
+
+impl fmt::Display for FileHoverCancelledEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("FileHoverCancelled")
+	}
+}
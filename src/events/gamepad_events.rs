@@ -0,0 +1,216 @@
+use super::Event;
+use super::EventBox;
+use super::EventCategory;
+use super::EventListener;
+use super::EventType;
+use crate::input::{GamepadAxis, GamepadButton, GamepadId};
+use crate::Float;
+use std::fmt;
+
+// Like window events, connection events always propagate further
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamepadConnectedEvent {
+	id: GamepadId,
+}
+
+impl GamepadConnectedEvent {
+	pub fn new(id: GamepadId) -> EventBox {
+		Box::new(Self { id })
+	}
+}
+
+impl Event for GamepadConnectedEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_gamepad_connected(self.id);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::GamepadConnected
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::GAMEPAD
+	}
+	fn record(&self) -> Option<String> {
+		Some(self.id.0.to_string())
+	}
+}
+
+impl fmt::Display for GamepadConnectedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "GamepadConnected({})", self.id.0)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamepadDisconnectedEvent {
+	id: GamepadId,
+}
+
+impl GamepadDisconnectedEvent {
+	pub fn new(id: GamepadId) -> EventBox {
+		Box::new(Self { id })
+	}
+}
+
+impl Event for GamepadDisconnectedEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_gamepad_disconnected(self.id);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::GamepadDisconnected
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::GAMEPAD
+	}
+	fn record(&self) -> Option<String> {
+		Some(self.id.0.to_string())
+	}
+}
+
+impl fmt::Display for GamepadDisconnectedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "GamepadDisconnected({})", self.id.0)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamepadPressedEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
+	handled: bool,
+	id: GamepadId,
+	button: GamepadButton,
+}
+
+impl GamepadPressedEvent {
+	pub fn new(id: GamepadId, button: GamepadButton) -> EventBox {
+		Box::new(Self {
+			handled: false,
+			id,
+			button,
+		})
+	}
+}
+
+impl Event for GamepadPressedEvent {
+	fn is_handled(&self) -> bool {
+		self.handled
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		debug_assert!(!self.handled);
+		self.handled = listener.on_gamepad_press(self.id, self.button);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::GamepadPressed
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::GAMEPAD
+	}
+	fn record(&self) -> Option<String> {
+		Some(format!("{} {}", self.id.0, self.button))
+	}
+}
+
+impl fmt::Display for GamepadPressedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "GamepadPressed({}, {})", self.id.0, self.button)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamepadReleasedEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
+	handled: bool,
+	id: GamepadId,
+	button: GamepadButton,
+}
+
+impl GamepadReleasedEvent {
+	pub fn new(id: GamepadId, button: GamepadButton) -> EventBox {
+		Box::new(Self {
+			handled: false,
+			id,
+			button,
+		})
+	}
+}
+
+impl Event for GamepadReleasedEvent {
+	fn is_handled(&self) -> bool {
+		self.handled
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		debug_assert!(!self.handled);
+		self.handled = listener.on_gamepad_release(self.id, self.button);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::GamepadReleased
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::GAMEPAD
+	}
+	fn record(&self) -> Option<String> {
+		Some(format!("{} {}", self.id.0, self.button))
+	}
+}
+
+impl fmt::Display for GamepadReleasedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "GamepadReleased({}, {})", self.id.0, self.button)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamepadAxisMovedEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
+	handled: bool,
+	id: GamepadId,
+	axis: GamepadAxis,
+	value: Float,
+}
+
+impl GamepadAxisMovedEvent {
+	pub fn new(id: GamepadId, axis: GamepadAxis, value: Float) -> EventBox {
+		Box::new(Self {
+			handled: false,
+			id,
+			axis,
+			value,
+		})
+	}
+}
+
+impl Event for GamepadAxisMovedEvent {
+	fn is_handled(&self) -> bool {
+		self.handled
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		debug_assert!(!self.handled);
+		self.handled = listener.on_gamepad_axis(self.id, self.axis, self.value);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::GamepadAxisMoved
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::GAMEPAD
+	}
+	fn record(&self) -> Option<String> {
+		Some(format!("{} {} {}", self.id.0, self.axis, self.value))
+	}
+}
+
+impl fmt::Display for GamepadAxisMovedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "GamepadAxisMoved({}, {}: {})", self.id.0, self.axis, self.value)
+	}
+}
@@ -1,19 +1,26 @@
 use super::Event;
 use super::EventBox;
+use super::EventCategory;
 use super::EventListener;
-use crate::input::Button;
+use super::EventType;
+use super::recording;
+use crate::input::Key;
+use std::fmt;
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyPressedEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
 	handled: bool,
-	button: Button,
+	key: Key,
 	repeat: bool,
 }
 
 impl KeyPressedEvent {
-	pub fn new(button: Button, repeat: bool) -> EventBox {
+	pub fn new<K: Into<Key>>(key: K, repeat: bool) -> EventBox {
 		Box::new(Self {
 			handled: false,
-			button,
+			key: key.into(),
 			repeat,
 		})
 	}
@@ -23,22 +30,44 @@ impl Event for KeyPressedEvent {
 	fn is_handled(&self) -> bool {
 		self.handled
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
 		debug_assert!(!self.handled);
-		self.handled = listener.on_key_press(self.button, self.repeat);
+		self.handled = listener.on_key_press(self.key, self.repeat);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::KeyPressed
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::KEYBOARD
+	}
+	fn record(&self) -> Option<String> {
+		Some(format!("{} {}", recording::record_key(&self.key), self.repeat as u8))
 	}
 }
 
+impl fmt::Display for KeyPressedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.repeat {
+			write!(f, "KeyPressed({}, repeat)", self.key)
+		} else {
+			write!(f, "KeyPressed({})", self.key)
+		}
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyReleasedEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
 	handled: bool,
-	button: Button,
+	key: Key,
 }
 
 impl KeyReleasedEvent {
-	pub fn new(button: Button) -> EventBox {
+	pub fn new<K: Into<Key>>(key: K) -> EventBox {
 		Box::new(Self {
 			handled: false,
-			button,
+			key: key.into(),
 		})
 	}
 }
@@ -47,13 +76,31 @@ impl Event for KeyReleasedEvent {
 	fn is_handled(&self) -> bool {
 		self.handled
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
 		debug_assert!(!self.handled);
-		self.handled = listener.on_key_release(self.button);
+		self.handled = listener.on_key_release(self.key);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::KeyReleased
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::KEYBOARD
+	}
+	fn record(&self) -> Option<String> {
+		Some(recording::record_key(&self.key))
 	}
 }
 
+impl fmt::Display for KeyReleasedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "KeyReleased({})", self.key)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharWrittenEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
 	handled: bool,
 	which: char,
 }
@@ -71,8 +118,23 @@ impl Event for CharWrittenEvent {
 	fn is_handled(&self) -> bool {
 		self.handled
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
 		debug_assert!(!self.handled);
 		self.handled = listener.on_char_written(self.which);
 	}
+	fn event_type(&self) -> EventType {
+		EventType::CharWritten
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::KEYBOARD
+	}
+	fn record(&self) -> Option<String> {
+		Some((self.which as u32).to_string())
+	}
+}
+
+impl fmt::Display for CharWrittenEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CharWritten({:?})", self.which)
+	}
 }
@@ -1,18 +1,35 @@
+// Event constructors return an `EventBox` rather than `Self`
+#![allow(clippy::new_ret_no_self)]
+
 mod event;
+mod event_bus;
 mod event_listener;
 mod event_queue;
+mod event_sender;
+mod recording;
 
 mod application_events;
+mod custom_events;
+mod file_events;
+mod gamepad_events;
 mod keyboard_events;
 mod mouse_events;
+mod text_events;
 mod window_events;
 
 pub type EventBox = Box<dyn Event>;
 
 pub use self::application_events::*;
-pub use self::event::Event;
+pub use self::custom_events::*;
+pub use self::event::{Event, EventCategory, EventType};
+pub use self::event_bus::EventBus;
 pub use self::event_listener::EventListener;
-pub use self::event_queue::EventQueue;
+pub use self::event_queue::{Delay, EventQueue, Priority};
+pub use self::event_sender::EventSender;
+pub use self::file_events::*;
+pub use self::gamepad_events::*;
 pub use self::keyboard_events::*;
 pub use self::mouse_events::*;
+pub use self::recording::{EventRecorder, RecordedFrame, Recording};
+pub use self::text_events::*;
 pub use self::window_events::*;
@@ -1,10 +1,17 @@
 use super::Event;
 use super::EventBox;
+use super::EventCategory;
 use super::EventListener;
+use super::EventType;
 use crate::input::Button;
 use crate::vecs::Vec2;
+use crate::Float;
+use std::fmt;
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MousePressedEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
 	handled: bool,
 	button: Button,
 }
@@ -22,13 +29,31 @@ impl Event for MousePressedEvent {
 	fn is_handled(&self) -> bool {
 		self.handled
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
 		debug_assert!(!self.handled);
 		self.handled = listener.on_mouse_press(self.button);
 	}
+	fn event_type(&self) -> EventType {
+		EventType::MousePressed
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::MOUSE | EventCategory::MOUSE_BUTTON
+	}
+	fn record(&self) -> Option<String> {
+		Some(self.button.to_string())
+	}
 }
 
+impl fmt::Display for MousePressedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MousePressed({})", self.button)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MouseReleasedEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
 	handled: bool,
 	button: Button,
 }
@@ -46,22 +71,59 @@ impl Event for MouseReleasedEvent {
 	fn is_handled(&self) -> bool {
 		self.handled
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
 		debug_assert!(!self.handled);
 		self.handled = listener.on_mouse_release(self.button);
 	}
+	fn event_type(&self) -> EventType {
+		EventType::MouseReleased
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::MOUSE | EventCategory::MOUSE_BUTTON
+	}
+	fn record(&self) -> Option<String> {
+		Some(self.button.to_string())
+	}
 }
 
+impl fmt::Display for MouseReleasedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MouseReleased({})", self.button)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MouseMovedEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
 	handled: bool,
 	position: Vec2,
+	scale_factor: Float,
+	// Sent by the window itself, which sends it again when replaying
+	#[cfg_attr(feature = "serde", serde(skip))]
+	synthetic: bool,
 }
 
 impl MouseMovedEvent {
-	pub fn new(position: Vec2) -> EventBox {
+	/// `position` is in logical pixels from the top left of the window,
+	/// times `scale_factor` for physical pixels.
+	pub fn new(position: Vec2, scale_factor: Float) -> EventBox {
+		Box::new(Self {
+			handled: false,
+			position,
+			scale_factor,
+			synthetic: false,
+		})
+	}
+
+	/// A mouse move caused by the application itself rather than the user,
+	/// which is left out of recordings.
+	pub(crate) fn synthetic(position: Vec2, scale_factor: Float) -> EventBox {
 		Box::new(Self {
 			handled: false,
 			position,
+			scale_factor,
+			synthetic: true,
 		})
 	}
 }
@@ -70,13 +132,82 @@ impl Event for MouseMovedEvent {
 	fn is_handled(&self) -> bool {
 		self.handled
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		debug_assert!(!self.handled);
+		self.handled = listener.on_mouse_move(self.position, self.scale_factor);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::MouseMoved
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::MOUSE
+	}
+	fn is_coalescable(&self) -> bool {
+		true
+	}
+	fn record(&self) -> Option<String> {
+		if self.synthetic {
+			return None;
+		}
+		Some(format!("{} {} {}", self.position.x, self.position.y, self.scale_factor))
+	}
+}
+
+impl fmt::Display for MouseMovedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MouseMoved({}, {} @{}x)", self.position.x, self.position.y, self.scale_factor)
+	}
+}
+
+/// Raw motion reported by the mouse itself, not affected by pointer
+/// acceleration or the cursor being stuck at the edge of the screen. Only
+/// delivered to focused windows.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MouseMotionEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
+	handled: bool,
+	delta: Vec2,
+}
+
+impl MouseMotionEvent {
+	pub fn new(delta: Vec2) -> EventBox {
+		Box::new(Self {
+			handled: false,
+			delta,
+		})
+	}
+}
+
+impl Event for MouseMotionEvent {
+	fn is_handled(&self) -> bool {
+		self.handled
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
 		debug_assert!(!self.handled);
-		self.handled = listener.on_mouse_move(self.position);
+		self.handled = listener.on_mouse_motion(self.delta);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::MouseMotion
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::MOUSE
+	}
+	fn record(&self) -> Option<String> {
+		Some(format!("{} {}", self.delta.x, self.delta.y))
+	}
+}
+
+impl fmt::Display for MouseMotionEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MouseMotion{}", self.delta)
 	}
 }
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MouseScrolledEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
 	handled: bool,
 	delta: Vec2,
 }
@@ -94,8 +225,23 @@ impl Event for MouseScrolledEvent {
 	fn is_handled(&self) -> bool {
 		self.handled
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
 		debug_assert!(!self.handled);
 		self.handled = listener.on_mouse_scroll(self.delta);
 	}
+	fn event_type(&self) -> EventType {
+		EventType::MouseScrolled
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::MOUSE
+	}
+	fn record(&self) -> Option<String> {
+		Some(format!("{} {}", self.delta.x, self.delta.y))
+	}
+}
+
+impl fmt::Display for MouseScrolledEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MouseScrolled{}", self.delta)
+	}
 }
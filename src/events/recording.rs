@@ -0,0 +1,409 @@
+use super::*;
+use crate::error::{Error, ErrorKind};
+use crate::input::{GamepadId, Key, Modifiers};
+use crate::vecs::Vec2;
+use crate::Float;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::str::{FromStr, SplitWhitespace};
+use std::time::Duration;
+
+const HEADER: &str = "rame-recording 1";
+
+/// Writes the events of a session to a file, frame by frame, so it can be
+/// replayed later with a `ReplayBackend`.
+///
+/// Recordings are plain text:
+///
+/// ```text
+/// rame-recording 1
+/// window Sandbox 1280 720 1
+/// frame 16666667
+/// KeyPressed W 17 0 0
+/// MouseMoved 300.5 200 1
+/// frame 16701203
+/// KeyReleased W 17 0
+/// ```
+///
+/// Frame durations are in nanoseconds. Events that can't be recorded, like
+/// custom events, are left out.
+pub struct EventRecorder {
+	writer: BufWriter<File>,
+}
+
+impl EventRecorder {
+	/// Creates the file at `path`, replacing it if it exists, and writes the
+	/// header describing the recorded window. `width` and `height` are in
+	/// logical pixels.
+	pub fn create<P: AsRef<Path>>(
+		path: P,
+		title: &str,
+		width: u32,
+		height: u32,
+		scale_factor: Float,
+	) -> Result<EventRecorder, Error> {
+		let path = path.as_ref();
+		let file = File::create(path).map_err(|err| Error::from(err).in_file(path))?;
+		let mut writer = BufWriter::new(file);
+		writeln!(writer, "{}", HEADER)?;
+		writeln!(writer, "window {} {} {} {}", escape(title), width, height, scale_factor)?;
+		Ok(EventRecorder { writer })
+	}
+
+	pub fn begin_frame(&mut self, delta: Duration) -> Result<(), Error> {
+		writeln!(self.writer, "frame {}", delta.as_nanos())?;
+		Ok(())
+	}
+
+	pub fn record(&mut self, event: &dyn Event) -> Result<(), Error> {
+		if let Some(fields) = event.record() {
+			if fields.is_empty() {
+				writeln!(self.writer, "{}", event.name())?;
+			} else {
+				writeln!(self.writer, "{} {}", event.name(), fields)?;
+			}
+		}
+		Ok(())
+	}
+
+	pub fn flush(&mut self) -> Result<(), Error> {
+		self.writer.flush()?;
+		Ok(())
+	}
+}
+
+#[derive(Debug)]
+pub struct RecordedFrame {
+	pub delta: Duration,
+	pub events: Vec<EventBox>,
+}
+
+/// A session written by an `EventRecorder`
+#[derive(Debug)]
+pub struct Recording {
+	pub title: String,
+	pub width: u32,
+	pub height: u32,
+	/// The scale factor of the window when recording started
+	pub scale_factor: Float,
+	pub frames: Vec<RecordedFrame>,
+}
+
+impl Recording {
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Recording, Error> {
+		let path = path.as_ref();
+		let text = std::fs::read_to_string(path).map_err(|err| Error::from(err).in_file(path))?;
+		text.parse().map_err(|err: Error| err.in_file(path))
+	}
+}
+
+impl FromStr for Recording {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Recording, Error> {
+		let mut lines = s.lines().enumerate();
+		if lines.next().map(|(_, line)| line.trim()) != Some(HEADER) {
+			return Err(Error::with_message(ErrorKind::Parse, "not a rame recording"));
+		}
+
+		let mut recording = Recording {
+			title: String::new(),
+			width: 0,
+			height: 0,
+			scale_factor: 1.0,
+			frames: Vec::new(),
+		};
+		for (i, line) in lines {
+			let mut fields = Fields::new(line, i + 1);
+			let name = match fields.tokens.next() {
+				Some(name) => name,
+				None => continue,
+			};
+			match name {
+				"window" => {
+					recording.title = unescape(fields.next()?);
+					recording.width = fields.parse()?;
+					recording.height = fields.parse()?;
+					recording.scale_factor = fields.parse_or(1.0)?;
+				}
+				"frame" => recording.frames.push(RecordedFrame {
+					delta: Duration::from_nanos(fields.parse()?),
+					events: Vec::new(),
+				}),
+				_ => {
+					let event = parse_event(name, &mut fields)?;
+					match recording.frames.last_mut() {
+						Some(frame) => frame.events.push(event),
+						None => return Err(fields.malformed()),
+					}
+				}
+			}
+			fields.end()?;
+		}
+		Ok(recording)
+	}
+}
+
+fn parse_event(name: &str, fields: &mut Fields) -> Result<EventBox, Error> {
+	let event = match name {
+		"WindowClosed" => WindowClosedEvent::new(),
+		"WindowResized" => WindowResizedEvent::new(fields.parse()?, fields.parse()?, fields.parse_or(1.0)?),
+		"WindowFocus" => WindowFocusEvent::new(fields.parse::<u8>()? != 0),
+		"WindowMoved" => WindowMovedEvent::new(fields.parse()?, fields.parse()?),
+		"WindowMinimized" => WindowMinimizedEvent::new(fields.parse::<u8>()? != 0),
+		"DpiChanged" => DpiChangedEvent::new(fields.parse()?),
+		"CursorEntered" => CursorEnteredEvent::new(),
+		"CursorLeft" => CursorLeftEvent::new(),
+		"FileDropped" => FileDroppedEvent::new(unescape(fields.next()?)),
+		"FileHovered" => FileHoveredEvent::new(unescape(fields.next()?)),
+		"FileHoverCancelled" => FileHoverCancelledEvent::new(),
+		"KeyPressed" => KeyPressedEvent::new(fields.key()?, fields.parse::<u8>()? != 0),
+		"KeyReleased" => KeyReleasedEvent::new(fields.key()?),
+		"CharWritten" => match std::char::from_u32(fields.parse()?) {
+			Some(c) => CharWrittenEvent::new(c),
+			None => return Err(fields.malformed()),
+		},
+		"TextInput" => TextInputEvent::new(unescape(fields.next()?)),
+		"TextComposition" => TextCompositionEvent::new(unescape(fields.next()?), fields.optional()?),
+		"MousePressed" => MousePressedEvent::new(fields.parse()?),
+		"MouseReleased" => MouseReleasedEvent::new(fields.parse()?),
+		"MouseMoved" => MouseMovedEvent::new(fields.vec2()?, fields.parse_or(1.0)?),
+		"MouseMotion" => MouseMotionEvent::new(fields.vec2()?),
+		"MouseScrolled" => MouseScrolledEvent::new(fields.vec2()?),
+		"GamepadConnected" => GamepadConnectedEvent::new(GamepadId(fields.parse()?)),
+		"GamepadDisconnected" => GamepadDisconnectedEvent::new(GamepadId(fields.parse()?)),
+		"GamepadPressed" => GamepadPressedEvent::new(GamepadId(fields.parse()?), fields.parse()?),
+		"GamepadReleased" => GamepadReleasedEvent::new(GamepadId(fields.parse()?), fields.parse()?),
+		"GamepadAxisMoved" => {
+			GamepadAxisMovedEvent::new(GamepadId(fields.parse()?), fields.parse()?, fields.parse()?)
+		}
+		_ => {
+			return Err(Error::with_message(
+				ErrorKind::Parse,
+				format!("unknown event \"{}\" on line {}", name, fields.line),
+			))
+		}
+	};
+	Ok(event)
+}
+
+/// The whitespace separated fields of a line in a recording
+struct Fields<'a> {
+	tokens: SplitWhitespace<'a>,
+	line: usize,
+}
+
+impl<'a> Fields<'a> {
+	fn new(line: &'a str, number: usize) -> Fields<'a> {
+		Fields {
+			tokens: line.split_whitespace(),
+			line: number,
+		}
+	}
+
+	fn malformed(&self) -> Error {
+		Error::with_message(ErrorKind::Parse, format!("malformed recording on line {}", self.line))
+	}
+
+	fn next(&mut self) -> Result<&'a str, Error> {
+		match self.tokens.next() {
+			Some(token) => Ok(token),
+			None => Err(self.malformed()),
+		}
+	}
+
+	fn parse<T: FromStr>(&mut self) -> Result<T, Error> {
+		let token = self.next()?;
+		token.parse().map_err(|_| self.malformed())
+	}
+
+	/// A trailing value missing from older recordings
+	fn parse_or<T: FromStr>(&mut self, default: T) -> Result<T, Error> {
+		match self.tokens.next() {
+			Some(token) => token.parse().map_err(|_| self.malformed()),
+			None => Ok(default),
+		}
+	}
+
+	/// A value that is written as `-` when absent
+	fn optional<T: FromStr>(&mut self) -> Result<Option<T>, Error> {
+		match self.next()? {
+			"-" => Ok(None),
+			token => token.parse().map(Some).map_err(|_| self.malformed()),
+		}
+	}
+
+	fn key(&mut self) -> Result<Key, Error> {
+		let button = self.parse()?;
+		let scancode = self.optional()?;
+		let modifiers = Modifiers::from_bits(self.parse()?);
+		Ok(Key::new(button, scancode, modifiers))
+	}
+
+	fn vec2(&mut self) -> Result<Vec2, Error> {
+		Ok(Vec2::new(self.parse()?, self.parse()?))
+	}
+
+	/// Fails if there are fields left over
+	fn end(&mut self) -> Result<(), Error> {
+		match self.tokens.next() {
+			Some(_) => Err(self.malformed()),
+			None => Ok(()),
+		}
+	}
+}
+
+/// Formats a key as the fields `button scancode modifiers`
+pub(crate) fn record_key(key: &Key) -> String {
+	let scancode = match key.scancode {
+		Some(scancode) => scancode.to_string(),
+		None => "-".to_string(),
+	};
+	format!("{} {} {}", key.button, scancode, key.modifiers.bits())
+}
+
+/// Escapes `text` so that it forms a single field without whitespace
+pub(crate) fn escape(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for c in text.chars() {
+		match c {
+			'\\' => escaped.push_str("\\\\"),
+			' ' => escaped.push_str("\\s"),
+			'\t' => escaped.push_str("\\t"),
+			'\n' => escaped.push_str("\\n"),
+			'\r' => escaped.push_str("\\r"),
+			c if c.is_whitespace() || c.is_control() => escaped.push_str(&format!("\\u{:x};", c as u32)),
+			c => escaped.push(c),
+		}
+	}
+	// An empty field would disappear when splitting on whitespace
+	if escaped.is_empty() {
+		escaped.push_str("\\e");
+	}
+	escaped
+}
+
+fn unescape(field: &str) -> String {
+	let mut text = String::with_capacity(field.len());
+	let mut chars = field.chars();
+	while let Some(c) = chars.next() {
+		if c != '\\' {
+			text.push(c);
+			continue;
+		}
+		match chars.next() {
+			Some('s') => text.push(' '),
+			Some('t') => text.push('\t'),
+			Some('n') => text.push('\n'),
+			Some('r') => text.push('\r'),
+			Some('u') => {
+				let code: String = chars.by_ref().take_while(|&c| c != ';').collect();
+				let c = u32::from_str_radix(&code, 16).ok().and_then(std::char::from_u32);
+				text.push(c.unwrap_or(std::char::REPLACEMENT_CHARACTER));
+			}
+			Some('e') => {}
+			Some(c) => text.push(c),
+			None => {}
+		}
+	}
+	text
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::application::Application;
+	use crate::input::Button;
+	use crate::window::{HeadlessBackend, Window, WindowConfig};
+
+	#[test]
+	fn escapes_fields() {
+		let texts = ["", "plain", "two words", "tab\tnew\nline\r", "back\\slash \\s", "\u{1}\u{3000}é"];
+		for text in &texts {
+			let escaped = escape(text);
+			assert!(!escaped.is_empty());
+			assert!(!escaped.contains(char::is_whitespace));
+			assert_eq!(unescape(&escaped), *text);
+		}
+	}
+
+	#[test]
+	fn parses_recordings() {
+		let text = "rame-recording 1\n\
+			window My\\sGame 1280 720 2\n\
+			frame 16666667\n\
+			KeyPressed W 17 0 0\n\
+			MouseMoved 300.5 200 2\n\
+			\n\
+			frame 16701203\n\
+			KeyReleased W 17 0\n";
+		let recording: Recording = text.parse().unwrap();
+		assert_eq!(recording.title, "My Game");
+		assert_eq!((recording.width, recording.height), (1280, 720));
+		assert_eq!(recording.scale_factor, 2.0);
+		assert_eq!(recording.frames.len(), 2);
+		assert_eq!(recording.frames[0].delta, Duration::from_nanos(16_666_667));
+		let types: Vec<_> = recording.frames[0].events.iter().map(|event| event.event_type()).collect();
+		assert_eq!(types, [EventType::KeyPressed, EventType::MouseMoved]);
+		assert_eq!(recording.frames[1].events[0].event_type(), EventType::KeyReleased);
+	}
+
+	#[test]
+	fn defaults_the_scale_factor() {
+		let recording: Recording = "rame-recording 1\nwindow Old 640 480\n".parse().unwrap();
+		assert_eq!(recording.scale_factor, 1.0);
+	}
+
+	#[test]
+	fn rejects_malformed_recordings() {
+		let cases = [
+			"",
+			"rame-recording 2\n",
+			"rame-recording 1\nKeyReleased W 17 0\n",
+			"rame-recording 1\nframe 10\nKeyReleased W\n",
+			"rame-recording 1\nframe 10\nWindowClosed extra\n",
+			"rame-recording 1\nframe ten\n",
+			"rame-recording 1\nframe 10\nNoSuchEvent\n",
+		];
+		for text in &cases {
+			let err = text.parse::<Recording>().err().unwrap();
+			assert_eq!(err.kind(), ErrorKind::Parse, "{:?}", text);
+		}
+	}
+
+	#[test]
+	fn replays_what_was_recorded() {
+		let _lock = crate::test_lock();
+		let dir = std::env::temp_dir();
+		let recorded = dir.join(format!("rame-test-{}-recorded", std::process::id()));
+		let replayed = dir.join(format!("rame-test-{}-replayed", std::process::id()));
+
+		let mut backend = HeadlessBackend::new().with_fixed_delta(Duration::from_millis(16));
+		backend.push_frame(vec![
+			KeyPressedEvent::new(Button::W, false),
+			MouseMovedEvent::new(Vec2::new(10.5, 20.0), 1.0),
+		]);
+		backend.push_empty_frames(1);
+		backend.push_frame(vec![
+			KeyReleasedEvent::new(Button::W),
+			CharWrittenEvent::new(' '),
+			MouseScrolledEvent::new(Vec2::new(0.0, -1.0)),
+		]);
+		let config = WindowConfig::new("Replay test", 640, 480);
+		let mut app = Application::with_window(Window::headless(config, backend)).unwrap();
+		app.record_to(&recorded).unwrap();
+		app.start().unwrap();
+
+		let mut app = Application::replay(&recorded).unwrap();
+		app.record_to(&replayed).unwrap();
+		app.start().unwrap();
+
+		let recorded_text = std::fs::read_to_string(&recorded).unwrap();
+		let replayed_text = std::fs::read_to_string(&replayed).unwrap();
+		std::fs::remove_file(&recorded).unwrap();
+		std::fs::remove_file(&replayed).unwrap();
+		assert!(recorded_text.contains("KeyPressed W"));
+		assert_eq!(recorded_text, replayed_text);
+	}
+}
@@ -0,0 +1,105 @@
+use super::Event;
+use super::EventBox;
+use super::EventCategory;
+use super::EventListener;
+use super::EventType;
+use super::recording;
+use std::fmt;
+
+// Text events are only dispatched to layers that want text input,
+// see `Layer::wants_text_input`
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextInputEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
+	handled: bool,
+	text: String,
+}
+
+impl TextInputEvent {
+	pub fn new<S: Into<String>>(text: S) -> EventBox {
+		Box::new(Self {
+			handled: false,
+			text: text.into(),
+		})
+	}
+}
+
+impl Event for TextInputEvent {
+	fn is_handled(&self) -> bool {
+		self.handled
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		debug_assert!(!self.handled);
+		self.handled = listener.on_text_input(&self.text);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::TextInput
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::TEXT
+	}
+	fn record(&self) -> Option<String> {
+		Some(recording::escape(&self.text))
+	}
+}
+
+impl fmt::Display for TextInputEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "TextInput({:?})", self.text)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextCompositionEvent {
+	#[cfg_attr(feature = "serde", serde(skip))]
+	handled: bool,
+	text: String,
+	cursor: Option<usize>,
+}
+
+impl TextCompositionEvent {
+	/// `cursor` is a byte offset into `text`. An empty `text` means the
+	/// composition was cancelled or committed.
+	pub fn new<S: Into<String>>(text: S, cursor: Option<usize>) -> EventBox {
+		Box::new(Self {
+			handled: false,
+			text: text.into(),
+			cursor,
+		})
+	}
+}
+
+impl Event for TextCompositionEvent {
+	fn is_handled(&self) -> bool {
+		self.handled
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		debug_assert!(!self.handled);
+		self.handled = listener.on_text_composition(&self.text, self.cursor);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::TextComposition
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::INPUT | EventCategory::TEXT
+	}
+	fn record(&self) -> Option<String> {
+		let cursor = match self.cursor {
+			Some(cursor) => cursor.to_string(),
+			None => "-".to_string(),
+		};
+		Some(format!("{} {}", recording::escape(&self.text), cursor))
+	}
+}
+
+impl fmt::Display for TextCompositionEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.cursor {
+			Some(cursor) => write!(f, "TextComposition({:?}, cursor: {})", self.text, cursor),
+			None => write!(f, "TextComposition({:?})", self.text),
+		}
+	}
+}
@@ -1,10 +1,16 @@
 use super::Event;
-use super::EventListener;
 use super::EventBox;
+use super::EventCategory;
+use super::EventListener;
+use super::EventType;
+use crate::Float;
+use std::fmt;
 
 // Window events should always propagate further, therefore
 // their `is_handled` function always return `false`
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WindowClosedEvent;
 
 impl WindowClosedEvent {
@@ -17,27 +23,331 @@ impl Event for WindowClosedEvent {
 	fn is_handled(&self) -> bool {
 		false
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
 		listener.on_window_closed();
 	}
+	fn event_type(&self) -> EventType {
+		EventType::WindowClosed
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW
+	}
+	fn record(&self) -> Option<String> {
+		Some(String::new())
+	}
 }
 
+impl fmt::Display for WindowClosedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("WindowClosed")
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WindowResizedEvent {
 	width: u32,
 	height: u32,
+	scale_factor: Float,
 }
 
 impl WindowResizedEvent {
+	/// `width` and `height` are in logical pixels, times `scale_factor` for
+	/// physical pixels.
+	pub fn new(width: u32, height: u32, scale_factor: Float) -> EventBox {
+		Box::new(Self {
+			width,
+			height,
+			scale_factor,
+		})
+	}
+}
+
+impl Event for WindowResizedEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_window_resize(self.width, self.height, self.scale_factor);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::WindowResized
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW
+	}
+	fn is_coalescable(&self) -> bool {
+		true
+	}
+	fn record(&self) -> Option<String> {
+		Some(format!("{} {} {}", self.width, self.height, self.scale_factor))
+	}
+}
+
+impl fmt::Display for WindowResizedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "WindowResized({}x{} @{}x)", self.width, self.height, self.scale_factor)
+	}
+}
+
+/// Sent by the window after it resized its framebuffer and viewport to a
+/// new physical size. Not sent while the window is minimized.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FramebufferResizedEvent {
+	width: u32,
+	height: u32,
+}
+
+impl FramebufferResizedEvent {
 	pub fn new(width: u32, height: u32) -> EventBox {
 		Box::new(Self { width, height })
 	}
 }
 
-impl Event for WindowResizedEvent {
+impl Event for FramebufferResizedEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_framebuffer_resize(self.width, self.height);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::FramebufferResized
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW
+	}
+	fn is_coalescable(&self) -> bool {
+		true
+	}
+	// Not recorded, as the window sends it again when replaying the resize
+}
+
+impl fmt::Display for FramebufferResizedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "FramebufferResized({}x{})", self.width, self.height)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowFocusEvent {
+	focused: bool,
+}
+
+impl WindowFocusEvent {
+	pub fn new(focused: bool) -> EventBox {
+		Box::new(Self { focused })
+	}
+}
+
+impl Event for WindowFocusEvent {
 	fn is_handled(&self) -> bool {
 		false
 	}
-	fn dispatch(&mut self, listener: &mut EventListener) {
-		listener.on_window_resize(self.width, self.height);
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_window_focus(self.focused);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::WindowFocus
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW
+	}
+	fn record(&self) -> Option<String> {
+		Some((self.focused as u8).to_string())
+	}
+}
+
+impl fmt::Display for WindowFocusEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "WindowFocus({})", self.focused)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowMovedEvent {
+	x: i32,
+	y: i32,
+}
+
+impl WindowMovedEvent {
+	/// `x` and `y` are the position of the window's top left corner on the
+	/// desktop.
+	pub fn new(x: i32, y: i32) -> EventBox {
+		Box::new(Self { x, y })
+	}
+}
+
+impl Event for WindowMovedEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_window_move(self.x, self.y);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::WindowMoved
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW
+	}
+	fn is_coalescable(&self) -> bool {
+		true
+	}
+	fn record(&self) -> Option<String> {
+		Some(format!("{} {}", self.x, self.y))
+	}
+}
+
+impl fmt::Display for WindowMovedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "WindowMoved({}, {})", self.x, self.y)
+	}
+}
+
+/// Only sent on Windows, the one platform glutin reports minimizing on, as
+/// a resize to 0x0.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowMinimizedEvent {
+	minimized: bool,
+}
+
+impl WindowMinimizedEvent {
+	/// `minimized` is false when the window is restored
+	pub fn new(minimized: bool) -> EventBox {
+		Box::new(Self { minimized })
+	}
+}
+
+impl Event for WindowMinimizedEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_window_minimize(self.minimized);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::WindowMinimized
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW
+	}
+	fn record(&self) -> Option<String> {
+		Some((self.minimized as u8).to_string())
+	}
+}
+
+impl fmt::Display for WindowMinimizedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "WindowMinimized({})", self.minimized)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DpiChangedEvent {
+	scale_factor: Float,
+}
+
+impl DpiChangedEvent {
+	/// `scale_factor` is the number of physical pixels per logical pixel
+	pub fn new(scale_factor: Float) -> EventBox {
+		Box::new(Self { scale_factor })
+	}
+}
+
+impl Event for DpiChangedEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_dpi_change(self.scale_factor);
+	}
+	fn event_type(&self) -> EventType {
+		EventType::DpiChanged
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW
+	}
+	fn record(&self) -> Option<String> {
+		Some(self.scale_factor.to_string())
+	}
+}
+
+impl fmt::Display for DpiChangedEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DpiChanged({})", self.scale_factor)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CursorEnteredEvent;
+
+impl CursorEnteredEvent {
+	pub fn new() -> EventBox {
+		Box::new(CursorEnteredEvent)
+	}
+}
+
+impl Event for CursorEnteredEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_cursor_enter();
+	}
+	fn event_type(&self) -> EventType {
+		EventType::CursorEntered
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW | EventCategory::MOUSE
+	}
+	fn record(&self) -> Option<String> {
+		Some(String::new())
+	}
+}
+
+impl fmt::Display for CursorEnteredEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("CursorEntered")
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CursorLeftEvent;
+
+impl CursorLeftEvent {
+	pub fn new() -> EventBox {
+		Box::new(CursorLeftEvent)
+	}
+}
+
+impl Event for CursorLeftEvent {
+	fn is_handled(&self) -> bool {
+		false
+	}
+	fn dispatch(&mut self, listener: &mut dyn EventListener) {
+		listener.on_cursor_leave();
+	}
+	fn event_type(&self) -> EventType {
+		EventType::CursorLeft
+	}
+	fn categories(&self) -> EventCategory {
+		EventCategory::WINDOW | EventCategory::MOUSE
+	}
+	fn record(&self) -> Option<String> {
+		Some(String::new())
+	}
+}
+
+impl fmt::Display for CursorLeftEvent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("CursorLeft")
 	}
 }
@@ -0,0 +1,360 @@
+use super::{Button, InputState, Modifiers};
+use crate::error::{Error, ErrorKind};
+use crate::vecs::Vec2;
+use crate::Float;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A button, optionally combined with modifiers that have to be held for
+/// the binding to count as pressed, e.g. `Ctrl+S`. Either the left or the
+/// right modifier key will do.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Binding {
+	pub button: Button,
+	pub modifiers: Modifiers,
+}
+
+impl Binding {
+	pub fn new(button: Button) -> Binding {
+		Binding {
+			button,
+			modifiers: Modifiers::NONE,
+		}
+	}
+
+	pub fn with_modifiers(mut self, modifiers: Modifiers) -> Binding {
+		self.modifiers |= modifiers;
+		self
+	}
+
+	fn modifiers_held(&self, state: &InputState) -> bool {
+		state.get_modifiers().contains(self.modifiers)
+	}
+
+	pub fn is_pressed(&self, state: &InputState) -> bool {
+		self.modifiers_held(state) && state.is_pressed(self.button)
+	}
+
+	pub fn is_just_pressed(&self, state: &InputState) -> bool {
+		self.modifiers_held(state) && state.is_just_pressed(self.button)
+	}
+
+	pub fn is_just_released(&self, state: &InputState) -> bool {
+		self.modifiers_held(state) && state.is_just_released(self.button)
+	}
+}
+
+impl From<Button> for Binding {
+	fn from(button: Button) -> Binding {
+		Binding::new(button)
+	}
+}
+
+impl fmt::Display for Binding {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if !self.modifiers.is_empty() {
+			write!(f, "{}+", self.modifiers)?;
+		}
+		write!(f, "{}", self.button)
+	}
+}
+
+impl FromStr for Binding {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Binding, Error> {
+		let mut names: Vec<&str> = s.split('+').map(str::trim).collect();
+		// `split` always yields at least one item
+		let button = names.pop().unwrap().parse()?;
+		let mut modifiers = Modifiers::NONE;
+		for name in names {
+			modifiers |= parse_modifier(name)?;
+		}
+		Ok(Binding { button, modifiers })
+	}
+}
+
+fn parse_modifier(name: &str) -> Result<Modifiers, Error> {
+	match name {
+		"Shift" => Ok(Modifiers::SHIFT),
+		"Ctrl" => Ok(Modifiers::CTRL),
+		"Alt" => Ok(Modifiers::ALT),
+		"Super" => Ok(Modifiers::SUPER),
+		_ => Err(Error::with_message(ErrorKind::Parse, format!("unknown modifier \"{}\"", name))),
+	}
+}
+
+/// Action and axis names are saved as is, so they can't contain whitespace
+/// or `=`, or start with `#`.
+fn check_name(name: &str) -> Result<(), Error> {
+	let valid = !name.is_empty() && !name.starts_with('#') && !name.contains(|c: char| c.is_whitespace() || c == '=');
+	if valid {
+		Ok(())
+	} else {
+		Err(Error::with_message(ErrorKind::Parse, format!("invalid action or axis name \"{}\"", name)))
+	}
+}
+
+/// A one dimensional axis, valued -1, 0 or 1 depending on which of its
+/// positive and negative bindings are held.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+struct Axis {
+	positive: Vec<Binding>,
+	negative: Vec<Binding>,
+}
+
+/// Maps named actions and axes to buttons, making it possible for players
+/// to rebind their controls.
+///
+/// Actions are pressed when any of their bindings are. Axes combine a
+/// positive and a negative binding into a value between -1 and 1, and 2D
+/// axes combine two named axes into a `Vec2`.
+///
+/// Bindings can be saved to and loaded from a simple text format:
+///
+/// ```text
+/// # Lines starting with a '#' are ignored
+/// action jump = Space
+/// action jump = Enter
+/// action save = Ctrl+S
+/// axis move_x = D A
+/// axis move_y = W S
+/// axis2 move = move_x move_y
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ActionMap {
+	actions: BTreeMap<String, Vec<Binding>>,
+	axes: BTreeMap<String, Axis>,
+	axes2: BTreeMap<String, (String, String)>,
+}
+
+impl ActionMap {
+	pub fn new() -> ActionMap {
+		ActionMap::default()
+	}
+
+	/// Adds `binding` to the bindings of `action`. Fails if `action` can't
+	/// be saved, i.e. if it contains whitespace or `=`, or starts with `#`.
+	pub fn bind<B: Into<Binding>>(&mut self, action: &str, binding: B) -> Result<(), Error> {
+		check_name(action)?;
+		self.actions
+			.entry(action.to_string())
+			.or_default()
+			.push(binding.into());
+		Ok(())
+	}
+
+	/// Removes all bindings of `action`.
+	pub fn unbind(&mut self, action: &str) {
+		self.actions.remove(action);
+	}
+
+	pub fn get_bindings(&self, action: &str) -> &[Binding] {
+		match self.actions.get(action) {
+			Some(bindings) => bindings,
+			None => &[],
+		}
+	}
+
+	/// Adds a pair of bindings to `axis`, the first one making it positive
+	/// and the second negative.
+	pub fn bind_axis<P, N>(&mut self, axis: &str, positive: P, negative: N) -> Result<(), Error>
+	where
+		P: Into<Binding>,
+		N: Into<Binding>,
+	{
+		check_name(axis)?;
+		let axis = self.axes.entry(axis.to_string()).or_default();
+		axis.positive.push(positive.into());
+		axis.negative.push(negative.into());
+		Ok(())
+	}
+
+	/// Removes all bindings of `axis`.
+	pub fn unbind_axis(&mut self, axis: &str) {
+		self.axes.remove(axis);
+	}
+
+	/// Makes `axis` a 2D axis consisting of the axes named `x` and `y`.
+	pub fn bind_axis2(&mut self, axis: &str, x: &str, y: &str) -> Result<(), Error> {
+		check_name(axis)?;
+		check_name(x)?;
+		check_name(y)?;
+		self.axes2.insert(axis.to_string(), (x.to_string(), y.to_string()));
+		Ok(())
+	}
+
+	pub fn is_pressed(&self, action: &str) -> bool {
+		let state = super::state();
+		self.get_bindings(action).iter().any(|binding| binding.is_pressed(&state))
+	}
+
+	pub fn is_just_pressed(&self, action: &str) -> bool {
+		let state = super::state();
+		self.get_bindings(action).iter().any(|binding| binding.is_just_pressed(&state))
+	}
+
+	pub fn is_just_released(&self, action: &str) -> bool {
+		let state = super::state();
+		self.get_bindings(action).iter().any(|binding| binding.is_just_released(&state))
+	}
+
+	/// The value of `axis`, from -1 to 1. Unbound axes are always 0.
+	pub fn axis(&self, axis: &str) -> Float {
+		self.axis_value(&super::state(), axis)
+	}
+
+	/// The value of the 2D axis `axis`, never longer than 1 so diagonal
+	/// movement isn't faster than straight.
+	pub fn axis2(&self, axis: &str) -> Vec2 {
+		let state = super::state();
+		let value = match self.axes2.get(axis) {
+			Some((x, y)) => Vec2::new(self.axis_value(&state, x), self.axis_value(&state, y)),
+			None => Vec2::zero(),
+		};
+		if value.mag_sq() > 1.0 {
+			value.normalized()
+		} else {
+			value
+		}
+	}
+
+	fn axis_value(&self, state: &InputState, axis: &str) -> Float {
+		let axis = match self.axes.get(axis) {
+			Some(axis) => axis,
+			None => return 0.0,
+		};
+		let mut value = 0.0;
+		if axis.positive.iter().any(|binding| binding.is_pressed(state)) {
+			value += 1.0;
+		}
+		if axis.negative.iter().any(|binding| binding.is_pressed(state)) {
+			value -= 1.0;
+		}
+		value
+	}
+
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<ActionMap, Error> {
+		let path = path.as_ref();
+		let text = fs::read_to_string(path).map_err(|err| Error::from(err).in_file(path))?;
+		text.parse().map_err(|err: Error| err.in_file(path))
+	}
+
+	pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+		let path = path.as_ref();
+		fs::write(path, self.to_string()).map_err(|err| Error::from(err).in_file(path))?;
+		Ok(())
+	}
+
+	/// Adds the bindings of a line of the saved format
+	fn parse_line(&mut self, line: &str) -> Result<(), Error> {
+		let (head, value) = match line.find('=') {
+			Some(i) => (&line[..i], &line[i + 1..]),
+			None => return Err(Error::new(ErrorKind::Parse)),
+		};
+		let head: Vec<&str> = head.split_whitespace().collect();
+		let value: Vec<&str> = value.split_whitespace().collect();
+		match (head.as_slice(), value.as_slice()) {
+			(["action", name], [binding]) => self.bind(name, binding.parse::<Binding>()?),
+			(["axis", name], [positive, negative]) => {
+				self.bind_axis(name, positive.parse::<Binding>()?, negative.parse::<Binding>()?)
+			}
+			(["axis2", name], [x, y]) => self.bind_axis2(name, x, y),
+			_ => Err(Error::new(ErrorKind::Parse)),
+		}
+	}
+}
+
+impl fmt::Display for ActionMap {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (action, bindings) in &self.actions {
+			for binding in bindings {
+				writeln!(f, "action {} = {}", action, binding)?;
+			}
+		}
+		for (name, axis) in &self.axes {
+			for (positive, negative) in axis.positive.iter().zip(&axis.negative) {
+				writeln!(f, "axis {} = {} {}", name, positive, negative)?;
+			}
+		}
+		for (name, (x, y)) in &self.axes2 {
+			writeln!(f, "axis2 {} = {} {}", name, x, y)?;
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for ActionMap {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<ActionMap, Error> {
+		let mut map = ActionMap::new();
+		for (i, line) in s.lines().enumerate() {
+			let line = line.trim();
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+			map.parse_line(line).map_err(|err| {
+				let mut message = format!("malformed binding on line {}", i + 1);
+				if let Some(reason) = err.message() {
+					message = format!("{}: {}", message, reason);
+				}
+				Error::with_message(ErrorKind::Parse, message)
+			})?;
+		}
+		Ok(map)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_bindings() {
+		assert_eq!("S".parse::<Binding>().unwrap(), Binding::new(Button::S));
+		let binding = Binding::new(Button::S).with_modifiers(Modifiers::CTRL | Modifiers::SHIFT);
+		assert_eq!(" Shift + Ctrl+S".parse::<Binding>().unwrap(), binding);
+		assert_eq!(binding.to_string().parse::<Binding>().unwrap(), binding);
+		// Modifier keys are buttons of their own
+		assert_eq!("LCtrl".parse::<Binding>().unwrap(), Binding::new(Button::LCtrl));
+
+		for text in &["", "Ctrl+", "LCtrl+S", "Hyper+S", "Ctrl+Nothing"] {
+			let err = text.parse::<Binding>().unwrap_err();
+			assert_eq!(err.kind(), ErrorKind::Parse, "{:?}", text);
+		}
+	}
+
+	#[test]
+	fn rejects_malformed_lines() {
+		let err = "action jump = Space\n\naction save = Hyper+S".parse::<ActionMap>().unwrap_err();
+		assert_eq!(err.kind(), ErrorKind::Parse);
+		assert_eq!(err.message(), Some("malformed binding on line 3: unknown modifier \"Hyper\""));
+
+		for text in &["action jump", "action jump = Space Enter", "axis move_x = D", "action #jump = Space"] {
+			let err = text.parse::<ActionMap>().unwrap_err();
+			assert_eq!(err.kind(), ErrorKind::Parse, "{:?}", text);
+			assert!(err.message().unwrap().starts_with("malformed binding on line 1"), "{:?}", text);
+		}
+	}
+
+	#[test]
+	fn saves_and_loads_bindings() {
+		let mut map = ActionMap::new();
+		map.bind("jump", Button::Space).unwrap();
+		map.bind("jump", Button::Enter).unwrap();
+		map.bind("save", Binding::new(Button::S).with_modifiers(Modifiers::CTRL)).unwrap();
+		map.bind_axis("move_x", Button::D, Button::A).unwrap();
+		map.bind_axis("move_y", Button::W, Button::S).unwrap();
+		map.bind_axis2("move", "move_x", "move_y").unwrap();
+
+		let path = std::env::temp_dir().join(format!("rame-test-{}-actions", std::process::id()));
+		map.save(&path).unwrap();
+		let loaded = ActionMap::load(&path);
+		std::fs::remove_file(&path).unwrap();
+		assert_eq!(loaded.unwrap(), map);
+	}
+}
@@ -0,0 +1,256 @@
+use crate::error::{Error, ErrorKind};
+use crate::events::{self, EventBox, EventQueue};
+use crate::vecs::Vec2;
+use crate::Float;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamepadId(pub u32);
+
+/// Gamepad buttons, named after their position rather than their label
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadButton {
+	South,
+	East,
+	West,
+	North,
+	LeftBumper,
+	RightBumper,
+	LeftStick,
+	RightStick,
+	Select,
+	Start,
+	Mode,
+	DPadUp,
+	DPadDown,
+	DPadLeft,
+	DPadRight,
+}
+
+pub(crate) const GAMEPAD_BUTTON_COUNT: usize = GamepadButton::DPadRight as usize + 1;
+
+impl GamepadButton {
+	/// Every gamepad button, in declaration order
+	pub const ALL: [GamepadButton; GAMEPAD_BUTTON_COUNT] = {
+		use GamepadButton::*;
+		[
+			South, East, West, North, LeftBumper, RightBumper, LeftStick, RightStick, Select, Start,
+			Mode, DPadUp, DPadDown, DPadLeft, DPadRight,
+		]
+	};
+}
+
+/// Analog inputs. Sticks range from -1 to 1 with positive y being up,
+/// triggers from 0 to 1.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadAxis {
+	LeftStickX,
+	LeftStickY,
+	RightStickX,
+	RightStickY,
+	LeftTrigger,
+	RightTrigger,
+}
+
+pub(crate) const GAMEPAD_AXIS_COUNT: usize = GamepadAxis::RightTrigger as usize + 1;
+
+impl GamepadAxis {
+	/// Every gamepad axis, in declaration order
+	pub const ALL: [GamepadAxis; GAMEPAD_AXIS_COUNT] = {
+		use GamepadAxis::*;
+		[LeftStickX, LeftStickY, RightStickX, RightStickY, LeftTrigger, RightTrigger]
+	};
+}
+
+impl fmt::Display for GamepadButton {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Debug::fmt(self, f)
+	}
+}
+
+impl FromStr for GamepadButton {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<GamepadButton, Error> {
+		match GamepadButton::ALL.iter().find(|button| button.to_string() == s) {
+			Some(&button) => Ok(button),
+			None => Err(Error::with_message(ErrorKind::Parse, format!("unknown gamepad button \"{}\"", s))),
+		}
+	}
+}
+
+impl fmt::Display for GamepadAxis {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Debug::fmt(self, f)
+	}
+}
+
+impl FromStr for GamepadAxis {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<GamepadAxis, Error> {
+		match GamepadAxis::ALL.iter().find(|axis| axis.to_string() == s) {
+			Some(&axis) => Ok(axis),
+			None => Err(Error::with_message(ErrorKind::Parse, format!("unknown gamepad axis \"{}\"", s))),
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadStick {
+	Left,
+	Right,
+}
+
+impl GamepadId {
+	pub fn is_connected(self) -> bool {
+		super::state().is_gamepad_connected(self)
+	}
+	pub fn is_pressed(self, button: GamepadButton) -> bool {
+		super::state().is_gamepad_pressed(self, button)
+	}
+	pub fn is_just_pressed(self, button: GamepadButton) -> bool {
+		super::state().is_gamepad_just_pressed(self, button)
+	}
+	pub fn is_just_released(self, button: GamepadButton) -> bool {
+		super::state().is_gamepad_just_released(self, button)
+	}
+	/// The value of `axis` with the deadzone applied
+	pub fn get_axis(self, axis: GamepadAxis) -> Float {
+		super::state().get_gamepad_axis(self, axis)
+	}
+	/// The position of `stick` with the deadzone applied
+	pub fn get_stick(self, stick: GamepadStick) -> Vec2 {
+		super::state().get_gamepad_stick(self, stick)
+	}
+}
+
+/// The state of a single connected gamepad
+pub(crate) struct GamepadState {
+	pub id: GamepadId,
+	pub pressed: [bool; GAMEPAD_BUTTON_COUNT],
+	pub just_pressed: [bool; GAMEPAD_BUTTON_COUNT],
+	pub just_released: [bool; GAMEPAD_BUTTON_COUNT],
+	pub axes: [Float; GAMEPAD_AXIS_COUNT],
+}
+
+impl GamepadState {
+	pub fn new(id: GamepadId) -> GamepadState {
+		GamepadState {
+			id,
+			pressed: [false; GAMEPAD_BUTTON_COUNT],
+			just_pressed: [false; GAMEPAD_BUTTON_COUNT],
+			just_released: [false; GAMEPAD_BUTTON_COUNT],
+			axes: [0.0; GAMEPAD_AXIS_COUNT],
+		}
+	}
+}
+
+/// Something that produces gamepad events, such as a `VirtualGamepad` or,
+/// with the `gilrs` feature, a `GilrsSource` reading the actual gamepads.
+/// The windows don't report gamepads themselves. Sources are polled by the
+/// application once per frame.
+pub trait GamepadSource {
+	fn poll(&mut self, event_queue: &mut EventQueue);
+}
+
+struct VirtualGamepadState {
+	id: GamepadId,
+	connected: bool,
+	pending: VecDeque<EventBox>,
+}
+
+/// A gamepad controlled from code, for testing without hardware.
+///
+/// Clones share the same device, so one clone can be handed to the
+/// application as a `GamepadSource` while another is used to press buttons.
+#[derive(Clone)]
+pub struct VirtualGamepad {
+	state: Rc<RefCell<VirtualGamepadState>>,
+}
+
+impl VirtualGamepad {
+	pub fn new(id: GamepadId) -> VirtualGamepad {
+		VirtualGamepad {
+			state: Rc::new(RefCell::new(VirtualGamepadState {
+				id,
+				connected: false,
+				pending: VecDeque::new(),
+			})),
+		}
+	}
+
+	pub fn get_id(&self) -> GamepadId {
+		self.state.borrow().id
+	}
+
+	pub fn is_connected(&self) -> bool {
+		self.state.borrow().connected
+	}
+
+	pub fn connect(&self) {
+		let mut state = self.state.borrow_mut();
+		if !state.connected {
+			state.connected = true;
+			let event = events::GamepadConnectedEvent::new(state.id);
+			state.pending.push_back(event);
+		}
+	}
+
+	pub fn disconnect(&self) {
+		let mut state = self.state.borrow_mut();
+		if state.connected {
+			state.connected = false;
+			let event = events::GamepadDisconnectedEvent::new(state.id);
+			state.pending.push_back(event);
+		}
+	}
+
+	/// Input on a disconnected gamepad is ignored
+	fn push(&self, event: impl FnOnce(GamepadId) -> EventBox) {
+		let mut state = self.state.borrow_mut();
+		if state.connected {
+			let event = event(state.id);
+			state.pending.push_back(event);
+		}
+	}
+
+	pub fn press(&self, button: GamepadButton) {
+		self.push(|id| events::GamepadPressedEvent::new(id, button));
+	}
+
+	pub fn release(&self, button: GamepadButton) {
+		self.push(|id| events::GamepadReleasedEvent::new(id, button));
+	}
+
+	pub fn set_axis(&self, axis: GamepadAxis, value: Float) {
+		self.push(|id| events::GamepadAxisMovedEvent::new(id, axis, value));
+	}
+}
+
+impl GamepadSource for VirtualGamepad {
+	fn poll(&mut self, event_queue: &mut EventQueue) {
+		let mut state = self.state.borrow_mut();
+		while let Some(event) = state.pending.pop_front() {
+			event_queue.push(event);
+		}
+	}
+}
+
+/// Scales `value` so that everything within `deadzone` of zero becomes
+/// zero, while still reaching 1 at the extremes.
+pub(crate) fn apply_deadzone(value: Float, deadzone: Float) -> Float {
+	if value.abs() <= deadzone {
+		0.0
+	} else {
+		value.signum() * (value.abs() - deadzone) / (1.0 - deadzone)
+	}
+}
@@ -0,0 +1,110 @@
+use super::{GamepadAxis, GamepadButton, GamepadId, GamepadSource};
+use crate::error::{Error, ErrorKind};
+use crate::events::{self, EventQueue};
+use crate::Float;
+
+/// Every gamepad connected to the computer, read through gilrs. Requires
+/// the `gilrs` feature.
+pub struct GilrsSource {
+	gilrs: gilrs::Gilrs,
+	started: bool,
+}
+
+impl GilrsSource {
+	pub fn new() -> Result<GilrsSource, Error> {
+		match gilrs::Gilrs::new() {
+			Ok(gilrs) => Ok(GilrsSource { gilrs, started: false }),
+			Err(err) => {
+				let message = format!("failed to initialize gilrs: {}", err);
+				Err(Error::with_message(ErrorKind::Other, message))
+			}
+		}
+	}
+}
+
+fn to_id(id: gilrs::GamepadId) -> GamepadId {
+	GamepadId(usize::from(id) as u32)
+}
+
+fn to_button(button: gilrs::Button) -> Option<GamepadButton> {
+	use gilrs::Button::*;
+	Some(match button {
+		South => GamepadButton::South,
+		East => GamepadButton::East,
+		West => GamepadButton::West,
+		North => GamepadButton::North,
+		LeftTrigger => GamepadButton::LeftBumper,
+		RightTrigger => GamepadButton::RightBumper,
+		LeftThumb => GamepadButton::LeftStick,
+		RightThumb => GamepadButton::RightStick,
+		Select => GamepadButton::Select,
+		Start => GamepadButton::Start,
+		Mode => GamepadButton::Mode,
+		DPadUp => GamepadButton::DPadUp,
+		DPadDown => GamepadButton::DPadDown,
+		DPadLeft => GamepadButton::DPadLeft,
+		DPadRight => GamepadButton::DPadRight,
+		_ => return None,
+	})
+}
+
+fn to_axis(axis: gilrs::Axis) -> Option<GamepadAxis> {
+	use gilrs::Axis::*;
+	Some(match axis {
+		LeftStickX => GamepadAxis::LeftStickX,
+		LeftStickY => GamepadAxis::LeftStickY,
+		RightStickX => GamepadAxis::RightStickX,
+		RightStickY => GamepadAxis::RightStickY,
+		_ => return None,
+	})
+}
+
+/// gilrs reports the analog triggers as buttons with a value
+fn to_trigger(button: gilrs::Button) -> Option<GamepadAxis> {
+	match button {
+		gilrs::Button::LeftTrigger2 => Some(GamepadAxis::LeftTrigger),
+		gilrs::Button::RightTrigger2 => Some(GamepadAxis::RightTrigger),
+		_ => None,
+	}
+}
+
+impl GamepadSource for GilrsSource {
+	fn poll(&mut self, event_queue: &mut EventQueue) {
+		// gilrs only reports gamepads connected later on
+		if !self.started {
+			self.started = true;
+			for (id, _) in self.gilrs.gamepads() {
+				event_queue.push(events::GamepadConnectedEvent::new(to_id(id)));
+			}
+		}
+
+		while let Some(gilrs::Event { id, event, .. }) = self.gilrs.next_event() {
+			let id = to_id(id);
+			match event {
+				gilrs::EventType::Connected => event_queue.push(events::GamepadConnectedEvent::new(id)),
+				gilrs::EventType::Disconnected => event_queue.push(events::GamepadDisconnectedEvent::new(id)),
+				gilrs::EventType::ButtonPressed(button, _) => {
+					if let Some(button) = to_button(button) {
+						event_queue.push(events::GamepadPressedEvent::new(id, button));
+					}
+				}
+				gilrs::EventType::ButtonReleased(button, _) => {
+					if let Some(button) = to_button(button) {
+						event_queue.push(events::GamepadReleasedEvent::new(id, button));
+					}
+				}
+				gilrs::EventType::ButtonChanged(button, value, _) => {
+					if let Some(axis) = to_trigger(button) {
+						event_queue.push(events::GamepadAxisMovedEvent::new(id, axis, Float::from(value)));
+					}
+				}
+				gilrs::EventType::AxisChanged(axis, value, _) => {
+					if let Some(axis) = to_axis(axis) {
+						event_queue.push(events::GamepadAxisMovedEvent::new(id, axis, Float::from(value)));
+					}
+				}
+				_ => (),
+			}
+		}
+	}
+}
@@ -0,0 +1,262 @@
+use super::Button;
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitOrAssign};
+
+/// A set of modifier keys, where left and right variants aren't told apart
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+	pub const NONE: Modifiers = Modifiers(0);
+	pub const SHIFT: Modifiers = Modifiers(1);
+	pub const CTRL: Modifiers = Modifiers(1 << 1);
+	pub const ALT: Modifiers = Modifiers(1 << 2);
+	pub const SUPER: Modifiers = Modifiers(1 << 3);
+
+	pub fn from_bits(bits: u8) -> Modifiers {
+		Modifiers(bits & 0b1111)
+	}
+	pub fn bits(self) -> u8 {
+		self.0
+	}
+	pub fn is_empty(self) -> bool {
+		self.0 == 0
+	}
+	pub fn contains(self, other: Modifiers) -> bool {
+		self.0 & other.0 == other.0
+	}
+	pub fn shift(self) -> bool {
+		self.contains(Modifiers::SHIFT)
+	}
+	pub fn ctrl(self) -> bool {
+		self.contains(Modifiers::CTRL)
+	}
+	pub fn alt(self) -> bool {
+		self.contains(Modifiers::ALT)
+	}
+	pub fn super_key(self) -> bool {
+		self.contains(Modifiers::SUPER)
+	}
+}
+
+impl BitOr for Modifiers {
+	type Output = Modifiers;
+	fn bitor(self, other: Modifiers) -> Modifiers {
+		Modifiers(self.0 | other.0)
+	}
+}
+
+impl BitOrAssign for Modifiers {
+	fn bitor_assign(&mut self, other: Modifiers) {
+		self.0 |= other.0;
+	}
+}
+
+impl BitAnd for Modifiers {
+	type Output = Modifiers;
+	fn bitand(self, other: Modifiers) -> Modifiers {
+		Modifiers(self.0 & other.0)
+	}
+}
+
+impl fmt::Display for Modifiers {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let names = [
+			(Modifiers::CTRL, "Ctrl"),
+			(Modifiers::SHIFT, "Shift"),
+			(Modifiers::ALT, "Alt"),
+			(Modifiers::SUPER, "Super"),
+		];
+		let mut first = true;
+		for &(modifier, name) in &names {
+			if self.contains(modifier) {
+				if !first {
+					write!(f, "+")?;
+				}
+				write!(f, "{}", name)?;
+				first = false;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// A key on the keyboard, as reported by a key event
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Key {
+	/// The key according to the keyboard layout, e.g. `Button::Z` for the
+	/// key labelled Z
+	pub button: Button,
+	/// The platform specific code of the physical key, if known
+	pub scancode: Option<u32>,
+	/// The modifiers held when the event happened
+	pub modifiers: Modifiers,
+}
+
+impl Key {
+	pub fn new(button: Button, scancode: Option<u32>, modifiers: Modifiers) -> Key {
+		Key {
+			button,
+			scancode,
+			modifiers,
+		}
+	}
+
+	/// The button at this key's position on a US QWERTY keyboard, no matter
+	/// the active layout. Use this for controls that depend on where keys
+	/// are, like WASD.
+	///
+	/// Only letters, digits and punctuation move between layouts; for any
+	/// other key this is the same as `button`.
+	pub fn physical(&self) -> Button {
+		if !is_layout_dependent(self.button) {
+			return self.button;
+		}
+		match self.scancode.map(physical_button) {
+			None | Some(Button::Unknown) => self.button,
+			Some(button) => button,
+		}
+	}
+}
+
+impl From<Button> for Key {
+	/// A key without scancode or modifiers, e.g. for scripted events
+	fn from(button: Button) -> Key {
+		Key::new(button, None, Modifiers::NONE)
+	}
+}
+
+impl fmt::Display for Key {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if !self.modifiers.is_empty() {
+			write!(f, "{}+", self.modifiers)?;
+		}
+		write!(f, "{}", self.button)
+	}
+}
+
+fn is_layout_dependent(button: Button) -> bool {
+	use Button::*;
+	matches!(
+		button,
+		Unknown | Tilde | Num1 | Num2 | Num3 | Num4 | Num5 | Num6 | Num7 | Num8 | Num9 | Num0 | Q | W
+			| E | R | T | Y | U | I | O | P | A | S | D | F | G | H | J | K | L | Z | X | C | V | B
+			| N | M | Minus | Equals | BracketLeft | BracketRight | Backslash | Semicolon | Apostrophe
+			| Comma | Period | Slash
+	)
+}
+
+/// Maps scancodes of the main block of the keyboard to buttons. These are
+/// the PC scancodes reported on Windows and Linux.
+#[cfg(not(target_os = "macos"))]
+fn physical_button(scancode: u32) -> Button {
+	use Button::*;
+	match scancode {
+		2 => Num1,
+		3 => Num2,
+		4 => Num3,
+		5 => Num4,
+		6 => Num5,
+		7 => Num6,
+		8 => Num7,
+		9 => Num8,
+		10 => Num9,
+		11 => Num0,
+		12 => Minus,
+		13 => Equals,
+		16 => Q,
+		17 => W,
+		18 => E,
+		19 => R,
+		20 => T,
+		21 => Y,
+		22 => U,
+		23 => I,
+		24 => O,
+		25 => P,
+		26 => BracketLeft,
+		27 => BracketRight,
+		30 => A,
+		31 => S,
+		32 => D,
+		33 => F,
+		34 => G,
+		35 => H,
+		36 => J,
+		37 => K,
+		38 => L,
+		39 => Semicolon,
+		40 => Apostrophe,
+		41 => Tilde,
+		43 => Backslash,
+		44 => Z,
+		45 => X,
+		46 => C,
+		47 => V,
+		48 => B,
+		49 => N,
+		50 => M,
+		51 => Comma,
+		52 => Period,
+		53 => Slash,
+		_ => Unknown,
+	}
+}
+
+/// Maps macOS virtual key codes of the main block of the keyboard to buttons
+#[cfg(target_os = "macos")]
+fn physical_button(scancode: u32) -> Button {
+	use Button::*;
+	match scancode {
+		0 => A,
+		1 => S,
+		2 => D,
+		3 => F,
+		4 => H,
+		5 => G,
+		6 => Z,
+		7 => X,
+		8 => C,
+		9 => V,
+		11 => B,
+		12 => Q,
+		13 => W,
+		14 => E,
+		15 => R,
+		16 => Y,
+		17 => T,
+		18 => Num1,
+		19 => Num2,
+		20 => Num3,
+		21 => Num4,
+		22 => Num6,
+		23 => Num5,
+		24 => Equals,
+		25 => Num9,
+		26 => Num7,
+		27 => Minus,
+		28 => Num8,
+		29 => Num0,
+		30 => BracketRight,
+		31 => O,
+		32 => U,
+		33 => BracketLeft,
+		34 => I,
+		35 => P,
+		37 => L,
+		38 => J,
+		39 => Apostrophe,
+		40 => K,
+		41 => Semicolon,
+		42 => Backslash,
+		43 => Comma,
+		44 => Slash,
+		45 => N,
+		46 => M,
+		47 => Period,
+		50 => Tilde,
+		_ => Unknown,
+	}
+}
@@ -1,245 +1,453 @@
 use crate::vecs::Vec2;
+use crate::window::WindowId;
+use crate::Float;
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
+pub mod actions;
 pub mod button;
+pub mod gamepad;
+#[cfg(feature = "gilrs")]
+mod gilrs_source;
+pub mod keyboard;
 
+pub use self::actions::{ActionMap, Binding};
 pub use self::button::Button;
+pub use self::gamepad::{GamepadAxis, GamepadButton, GamepadId, GamepadSource, GamepadStick, VirtualGamepad};
+#[cfg(feature = "gilrs")]
+pub use self::gilrs_source::GilrsSource;
+pub use self::keyboard::{Key, Modifiers};
+use self::button::BUTTON_COUNT;
+use self::gamepad::GamepadState;
 
-pub static mut INPUT_STATE: InputState = InputState {
-	mouse_position: Vec2 { x: 0.0, y: 0.0 },
-	mouse_left: false,
-	mouse_right: false,
-	mouse_middle: false,
-	mouse4: false,
-	mouse5: false,
-	mouse6: false,
-	mouse7: false,
-	mouse8: false,
-	l_shift: false,
-	l_ctrl: false,
-	l_alt: false,
-	l_super: false,
-	r_shift: false,
-	r_ctrl: false,
-	r_alt: false,
-	r_super: false,
-	tab: false,
-	caps_lock: false,
-	backspace: false,
-	esc: false,
-	f1: false,
-	f2: false,
-	f3: false,
-	f4: false,
-	f5: false,
-	f6: false,
-	f7: false,
-	f8: false,
-	f9: false,
-	f10: false,
-	f11: false,
-	f12: false,
-	print_screen: false,
-	scroll_lock: false,
-	pause: false,
-	insert: false,
-	delete: false,
-	home: false,
-	end: false,
-	pg_up: false,
-	pg_down: false,
-	menu: false,
-	arrow_left: false,
-	arrow_up: false,
-	arrow_right: false,
-	arrow_down: false,
-	tilde: false,
-	num1: false,
-	num2: false,
-	num3: false,
-	num4: false,
-	num5: false,
-	num6: false,
-	num7: false,
-	num8: false,
-	num9: false,
-	num0: false,
-	q: false,
-	w: false,
-	e: false,
-	r: false,
-	t: false,
-	y: false,
-	u: false,
-	i: false,
-	o: false,
-	p: false,
-	a: false,
-	s: false,
-	d: false,
-	f: false,
-	g: false,
-	h: false,
-	j: false,
-	k: false,
-	l: false,
-	z: false,
-	x: false,
-	c: false,
-	v: false,
-	b: false,
-	n: false,
-	m: false,
-	space: false,
-	minus: false,
-	equals: false,
-	bracket_left: false,
-	bracket_right: false,
-	backslash: false,
-	semicolon: false,
-	apostrophe: false,
-	enter: false,
-	comma: false,
-	period: false,
-	slash: false,
-	numpad0: false,
-	numpad1: false,
-	numpad2: false,
-	numpad3: false,
-	numpad4: false,
-	numpad5: false,
-	numpad6: false,
-	numpad7: false,
-	numpad8: false,
-	numpad9: false,
-	numlock: false,
-	numpad_dec: false,
-	numpad_div: false,
-	numpad_mult: false,
-	numpad_sub: false,
-	numpad_add: false,
-	numpad_eq: false,
-	numpad_enter: false,
-};
+static INPUT_STATE: RwLock<InputState> = RwLock::new(InputState::new());
 
+/// The state of every input device, as of the last processed event.
+///
+/// The engine keeps a single global instance up to date, which can be
+/// read from any thread through `state`. Edges, i.e. whether a button was
+/// pressed or released, are tracked per frame and reset at the start of
+/// every frame.
 pub struct InputState {
-	pub mouse_position: Vec2,
-	pub mouse_left: bool,
-	pub mouse_right: bool,
-	pub mouse_middle: bool,
-	pub mouse4: bool,
-	pub mouse5: bool,
-	pub mouse6: bool,
-	pub mouse7: bool,
-	pub mouse8: bool,
-	pub l_shift: bool,
-	pub l_ctrl: bool,
-	pub l_alt: bool,
-	pub l_super: bool,
-	pub r_shift: bool,
-	pub r_ctrl: bool,
-	pub r_alt: bool,
-	pub r_super: bool,
-	pub tab: bool,
-	pub caps_lock: bool,
-	pub backspace: bool,
-	pub esc: bool,
-	pub f1: bool,
-	pub f2: bool,
-	pub f3: bool,
-	pub f4: bool,
-	pub f5: bool,
-	pub f6: bool,
-	pub f7: bool,
-	pub f8: bool,
-	pub f9: bool,
-	pub f10: bool,
-	pub f11: bool,
-	pub f12: bool,
-	pub print_screen: bool,
-	pub scroll_lock: bool,
-	pub pause: bool,
-	pub insert: bool,
-	pub delete: bool,
-	pub home: bool,
-	pub end: bool,
-	pub pg_up: bool,
-	pub pg_down: bool,
-	pub menu: bool,
-	pub arrow_left: bool,
-	pub arrow_up: bool,
-	pub arrow_right: bool,
-	pub arrow_down: bool,
-	pub tilde: bool,
-	pub num1: bool,
-	pub num2: bool,
-	pub num3: bool,
-	pub num4: bool,
-	pub num5: bool,
-	pub num6: bool,
-	pub num7: bool,
-	pub num8: bool,
-	pub num9: bool,
-	pub num0: bool,
-	pub q: bool,
-	pub w: bool,
-	pub e: bool,
-	pub r: bool,
-	pub t: bool,
-	pub y: bool,
-	pub u: bool,
-	pub i: bool,
-	pub o: bool,
-	pub p: bool,
-	pub a: bool,
-	pub s: bool,
-	pub d: bool,
-	pub f: bool,
-	pub g: bool,
-	pub h: bool,
-	pub j: bool,
-	pub k: bool,
-	pub l: bool,
-	pub z: bool,
-	pub x: bool,
-	pub c: bool,
-	pub v: bool,
-	pub b: bool,
-	pub n: bool,
-	pub m: bool,
-	pub space: bool,
-	pub minus: bool,
-	pub equals: bool,
-	pub bracket_left: bool,
-	pub bracket_right: bool,
-	pub backslash: bool,
-	pub semicolon: bool,
-	pub apostrophe: bool,
-	pub enter: bool,
-	pub comma: bool,
-	pub period: bool,
-	pub slash: bool,
-	pub numpad0: bool,
-	pub numpad1: bool,
-	pub numpad2: bool,
-	pub numpad3: bool,
-	pub numpad4: bool,
-	pub numpad5: bool,
-	pub numpad6: bool,
-	pub numpad7: bool,
-	pub numpad8: bool,
-	pub numpad9: bool,
-	pub numlock: bool,
-	pub numpad_dec: bool,
-	pub numpad_div: bool,
-	pub numpad_mult: bool,
-	pub numpad_sub: bool,
-	pub numpad_add: bool,
-	pub numpad_eq: bool,
-	pub numpad_enter: bool,
+	mouse_position: Vec2,
+	mouse_scale_factor: Float,
+	mouse_window: Option<WindowId>,
+	frame_mouse_position: Vec2,
+	mouse_motion: Vec2,
+	scroll_delta: Vec2,
+	pressed: [bool; BUTTON_COUNT],
+	physically_pressed: [bool; BUTTON_COUNT],
+	just_pressed: [bool; BUTTON_COUNT],
+	just_released: [bool; BUTTON_COUNT],
+	pressed_at: [Float; BUTTON_COUNT],
+	time: Float,
+	gamepads: Vec<GamepadState>,
+	gamepad_deadzone: Float,
+}
+
+impl InputState {
+	pub const fn new() -> InputState {
+		InputState {
+			mouse_position: Vec2 { x: 0.0, y: 0.0 },
+			mouse_scale_factor: 1.0,
+			mouse_window: None,
+			frame_mouse_position: Vec2 { x: 0.0, y: 0.0 },
+			mouse_motion: Vec2 { x: 0.0, y: 0.0 },
+			scroll_delta: Vec2 { x: 0.0, y: 0.0 },
+			pressed: [false; BUTTON_COUNT],
+			physically_pressed: [false; BUTTON_COUNT],
+			just_pressed: [false; BUTTON_COUNT],
+			just_released: [false; BUTTON_COUNT],
+			pressed_at: [0.0; BUTTON_COUNT],
+			time: 0.0,
+			gamepads: Vec::new(),
+			gamepad_deadzone: 0.15,
+		}
+	}
+
+	pub fn is_pressed(&self, button: Button) -> bool {
+		button != Button::Unknown && self.pressed[button as usize]
+	}
+
+	/// Whether the key at `button`'s position on a US QWERTY keyboard is
+	/// held, no matter the active layout. See `Key::physical`.
+	pub fn is_physically_pressed(&self, button: Button) -> bool {
+		button != Button::Unknown && self.physically_pressed[button as usize]
+	}
+
+	/// The modifiers currently held, worked out from the modifier keys
+	/// rather than taken from key events, which on some platforms report the
+	/// state from before the event.
+	pub fn get_modifiers(&self) -> Modifiers {
+		let mut modifiers = Modifiers::NONE;
+		if self.is_pressed(Button::LShift) || self.is_pressed(Button::RShift) {
+			modifiers |= Modifiers::SHIFT;
+		}
+		if self.is_pressed(Button::LCtrl) || self.is_pressed(Button::RCtrl) {
+			modifiers |= Modifiers::CTRL;
+		}
+		if self.is_pressed(Button::LAlt) || self.is_pressed(Button::RAlt) {
+			modifiers |= Modifiers::ALT;
+		}
+		if self.is_pressed(Button::LSuper) || self.is_pressed(Button::RSuper) {
+			modifiers |= Modifiers::SUPER;
+		}
+		modifiers
+	}
+
+	/// Whether `button` went down this frame
+	pub fn is_just_pressed(&self, button: Button) -> bool {
+		button != Button::Unknown && self.just_pressed[button as usize]
+	}
+
+	/// Whether `button` went up this frame
+	pub fn is_just_released(&self, button: Button) -> bool {
+		button != Button::Unknown && self.just_released[button as usize]
+	}
+
+	/// The time in seconds from when `button` was pressed until the start
+	/// of this frame, or zero if it isn't held.
+	pub fn held_time(&self, button: Button) -> Float {
+		if self.is_pressed(button) {
+			self.time - self.pressed_at[button as usize]
+		} else {
+			0.0
+		}
+	}
+
+	/// In logical pixels from the top left of the window
+	pub fn get_mouse_position(&self) -> Vec2 {
+		self.mouse_position
+	}
+
+	/// The window the cursor is over, which the mouse position is relative
+	/// to. `None` while it's outside of every window.
+	pub fn get_mouse_window(&self) -> Option<WindowId> {
+		self.mouse_window
+	}
+
+	/// In physical pixels, as used by the framebuffer
+	pub fn get_physical_mouse_position(&self) -> Vec2 {
+		self.mouse_position * self.mouse_scale_factor
+	}
+
+	/// How far the mouse has moved since the start of this frame
+	pub fn get_mouse_delta(&self) -> Vec2 {
+		self.mouse_position - self.frame_mouse_position
+	}
+
+	/// The sum of all raw mouse motion this frame, which keeps being
+	/// reported while the cursor is grabbed or stuck at the edge of the
+	/// screen. Meant for e.g. first person cameras.
+	pub fn get_mouse_motion(&self) -> Vec2 {
+		self.mouse_motion
+	}
+
+	/// The sum of all scrolling done this frame
+	pub fn get_scroll_delta(&self) -> Vec2 {
+		self.scroll_delta
+	}
+
+	pub fn get_gamepads(&self) -> Vec<GamepadId> {
+		self.gamepads.iter().map(|gamepad| gamepad.id).collect()
+	}
+
+	fn gamepad(&self, id: GamepadId) -> Option<&GamepadState> {
+		self.gamepads.iter().find(|gamepad| gamepad.id == id)
+	}
+
+	fn gamepad_mut(&mut self, id: GamepadId) -> Option<&mut GamepadState> {
+		self.gamepads.iter_mut().find(|gamepad| gamepad.id == id)
+	}
+
+	pub fn is_gamepad_connected(&self, id: GamepadId) -> bool {
+		self.gamepad(id).is_some()
+	}
+
+	pub fn is_gamepad_pressed(&self, id: GamepadId, button: GamepadButton) -> bool {
+		self.gamepad(id).is_some_and(|gamepad| gamepad.pressed[button as usize])
+	}
+
+	pub fn is_gamepad_just_pressed(&self, id: GamepadId, button: GamepadButton) -> bool {
+		self.gamepad(id).is_some_and(|gamepad| gamepad.just_pressed[button as usize])
+	}
+
+	pub fn is_gamepad_just_released(&self, id: GamepadId, button: GamepadButton) -> bool {
+		self.gamepad(id).is_some_and(|gamepad| gamepad.just_released[button as usize])
+	}
+
+	/// The value of `axis` with the deadzone applied to it alone. For sticks,
+	/// prefer `get_gamepad_stick` which takes both directions into account.
+	pub fn get_gamepad_axis(&self, id: GamepadId, axis: GamepadAxis) -> Float {
+		let value = self.get_raw_gamepad_axis(id, axis);
+		gamepad::apply_deadzone(value, self.gamepad_deadzone)
+	}
+
+	/// The value of `axis` as last reported by the device
+	pub fn get_raw_gamepad_axis(&self, id: GamepadId, axis: GamepadAxis) -> Float {
+		self.gamepad(id).map_or(0.0, |gamepad| gamepad.axes[axis as usize])
+	}
+
+	/// The position of `stick` with a radial deadzone applied
+	pub fn get_gamepad_stick(&self, id: GamepadId, stick: GamepadStick) -> Vec2 {
+		let (x, y) = match stick {
+			GamepadStick::Left => (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY),
+			GamepadStick::Right => (GamepadAxis::RightStickX, GamepadAxis::RightStickY),
+		};
+		let position = Vec2::new(self.get_raw_gamepad_axis(id, x), self.get_raw_gamepad_axis(id, y));
+		let magnitude = position.mag().min(1.0);
+		let scaled = gamepad::apply_deadzone(magnitude, self.gamepad_deadzone);
+		if scaled == 0.0 {
+			Vec2::zero()
+		} else {
+			position.normalized() * scaled
+		}
+	}
+
+	pub fn get_gamepad_deadzone(&self) -> Float {
+		self.gamepad_deadzone
+	}
+
+	/// Sets how far, from 0 to 1, an axis has to move from rest before
+	/// it's reported as anything but zero. Defaults to 0.15.
+	pub fn set_gamepad_deadzone(&mut self, deadzone: Float) {
+		self.gamepad_deadzone = deadzone.clamp(0.0, 0.99);
+	}
+
+	/// Forgets this frame's edges. `time` is the elapsed time of the new frame.
+	pub(crate) fn begin_frame(&mut self, time: Float) {
+		self.just_pressed = [false; BUTTON_COUNT];
+		self.just_released = [false; BUTTON_COUNT];
+		self.frame_mouse_position = self.mouse_position;
+		self.mouse_motion = Vec2::zero();
+		self.scroll_delta = Vec2::zero();
+		self.time = time;
+		for gamepad in &mut self.gamepads {
+			gamepad.just_pressed = Default::default();
+			gamepad.just_released = Default::default();
+		}
+	}
+
+	pub(crate) fn press(&mut self, button: Button) {
+		let i = button as usize;
+		if !self.pressed[i] {
+			self.pressed[i] = true;
+			self.just_pressed[i] = true;
+			self.pressed_at[i] = self.time;
+		}
+	}
+
+	pub(crate) fn release(&mut self, button: Button) {
+		let i = button as usize;
+		if self.pressed[i] {
+			self.pressed[i] = false;
+			self.just_released[i] = true;
+		}
+	}
+
+	pub(crate) fn press_key(&mut self, key: Key) {
+		self.press(key.button);
+		self.physically_pressed[key.physical() as usize] = true;
+	}
+
+	pub(crate) fn release_key(&mut self, key: Key) {
+		self.release(key.button);
+		self.physically_pressed[key.physical() as usize] = false;
+	}
+
+	pub(crate) fn set_mouse_position(&mut self, position: Vec2, scale_factor: Float, window: Option<WindowId>) {
+		self.mouse_position = position;
+		self.mouse_scale_factor = scale_factor;
+		if window.is_some() {
+			self.mouse_window = window;
+		}
+	}
+
+	pub(crate) fn enter_window(&mut self, window: Option<WindowId>) {
+		self.mouse_window = window;
+	}
+
+	pub(crate) fn leave_window(&mut self, window: Option<WindowId>) {
+		// Entering the next window may be reported before leaving this one
+		if self.mouse_window == window {
+			self.mouse_window = None;
+		}
+	}
+
+	pub(crate) fn move_mouse(&mut self, delta: Vec2) {
+		self.mouse_motion += delta;
+	}
+
+	pub(crate) fn scroll(&mut self, delta: Vec2) {
+		self.scroll_delta += delta;
+	}
+
+	pub(crate) fn connect_gamepad(&mut self, id: GamepadId) {
+		if !self.is_gamepad_connected(id) {
+			self.gamepads.push(GamepadState::new(id));
+		}
+	}
+
+	pub(crate) fn disconnect_gamepad(&mut self, id: GamepadId) {
+		self.gamepads.retain(|gamepad| gamepad.id != id);
+	}
+
+	pub(crate) fn press_gamepad(&mut self, id: GamepadId, button: GamepadButton) {
+		if let Some(gamepad) = self.gamepad_mut(id) {
+			let i = button as usize;
+			if !gamepad.pressed[i] {
+				gamepad.pressed[i] = true;
+				gamepad.just_pressed[i] = true;
+			}
+		}
+	}
+
+	pub(crate) fn release_gamepad(&mut self, id: GamepadId, button: GamepadButton) {
+		if let Some(gamepad) = self.gamepad_mut(id) {
+			let i = button as usize;
+			if gamepad.pressed[i] {
+				gamepad.pressed[i] = false;
+				gamepad.just_released[i] = true;
+			}
+		}
+	}
+
+	pub(crate) fn move_gamepad_axis(&mut self, id: GamepadId, axis: GamepadAxis, value: Float) {
+		if let Some(gamepad) = self.gamepad_mut(id) {
+			gamepad.axes[axis as usize] = value;
+		}
+	}
+}
+
+impl Default for InputState {
+	fn default() -> InputState {
+		InputState::new()
+	}
+}
+
+/// Locks the global input state for reading.
+///
+/// The lock is shared with other readers but blocks the engine from
+/// processing input events, so don't hold on to it for longer than needed.
+pub fn state() -> RwLockReadGuard<'static, InputState> {
+	INPUT_STATE.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub(crate) fn state_mut() -> RwLockWriteGuard<'static, InputState> {
+	INPUT_STATE.write().unwrap_or_else(PoisonError::into_inner)
 }
 
 pub fn get_mouse_position() -> Vec2 {
-	unsafe {
-		INPUT_STATE.mouse_position
+	state().get_mouse_position()
+}
+
+pub fn get_mouse_window() -> Option<WindowId> {
+	state().get_mouse_window()
+}
+
+pub fn get_physical_mouse_position() -> Vec2 {
+	state().get_physical_mouse_position()
+}
+
+pub fn get_mouse_delta() -> Vec2 {
+	state().get_mouse_delta()
+}
+
+pub fn get_mouse_motion() -> Vec2 {
+	state().get_mouse_motion()
+}
+
+pub fn get_scroll_delta() -> Vec2 {
+	state().get_scroll_delta()
+}
+
+pub fn get_gamepads() -> Vec<GamepadId> {
+	state().get_gamepads()
+}
+
+pub fn set_gamepad_deadzone(deadzone: Float) {
+	state_mut().set_gamepad_deadzone(deadzone);
+}
+
+pub fn get_modifiers() -> Modifiers {
+	state().get_modifiers()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn tracks_edges_per_frame() {
+		let mut state = InputState::new();
+		state.begin_frame(0.0);
+		state.press(Button::MouseLeft);
+		assert!(state.is_pressed(Button::MouseLeft));
+		assert!(state.is_just_pressed(Button::MouseLeft));
+		assert!(!state.is_just_released(Button::MouseLeft));
+
+		state.begin_frame(0.5);
+		assert!(state.is_pressed(Button::MouseLeft));
+		assert!(!state.is_just_pressed(Button::MouseLeft));
+		assert_eq!(state.held_time(Button::MouseLeft), 0.5);
+
+		state.release(Button::MouseLeft);
+		assert!(!state.is_pressed(Button::MouseLeft));
+		assert!(state.is_just_released(Button::MouseLeft));
+		assert_eq!(state.held_time(Button::MouseLeft), 0.0);
+
+		state.begin_frame(1.0);
+		assert!(!state.is_just_released(Button::MouseLeft));
+	}
+
+	#[test]
+	fn keeps_both_edges_of_a_tap_within_a_frame() {
+		let mut state = InputState::new();
+		state.begin_frame(0.0);
+		state.press(Button::Space);
+		state.release(Button::Space);
+		assert!(!state.is_pressed(Button::Space));
+		assert!(state.is_just_pressed(Button::Space));
+		assert!(state.is_just_released(Button::Space));
+	}
+
+	#[test]
+	fn ignores_repeated_presses_and_releases() {
+		let mut state = InputState::new();
+		state.begin_frame(0.0);
+		state.press(Button::A);
+		state.begin_frame(1.0);
+		state.press(Button::A);
+		assert!(!state.is_just_pressed(Button::A));
+		assert_eq!(state.held_time(Button::A), 1.0);
+
+		state.release(Button::B);
+		assert!(!state.is_just_released(Button::B));
+	}
+
+	#[test]
+	fn tracks_gamepad_edges() {
+		let id = GamepadId(0);
+		let mut state = InputState::new();
+		state.press_gamepad(id, GamepadButton::South);
+		assert!(!state.is_gamepad_pressed(id, GamepadButton::South));
+
+		state.connect_gamepad(id);
+		state.press_gamepad(id, GamepadButton::South);
+		assert!(state.is_gamepad_just_pressed(id, GamepadButton::South));
+		state.begin_frame(0.0);
+		assert!(state.is_gamepad_pressed(id, GamepadButton::South));
+		assert!(!state.is_gamepad_just_pressed(id, GamepadButton::South));
+		state.release_gamepad(id, GamepadButton::South);
+		assert!(state.is_gamepad_just_released(id, GamepadButton::South));
+	}
+
+	#[test]
+	fn derives_modifiers_from_held_keys() {
+		let mut state = InputState::new();
+		state.press_key(Key::from(Button::RCtrl));
+		state.press_key(Key::from(Button::LShift));
+		assert_eq!(state.get_modifiers(), Modifiers::CTRL | Modifiers::SHIFT);
+		state.release_key(Key::from(Button::RCtrl));
+		assert_eq!(state.get_modifiers(), Modifiers::SHIFT);
 	}
 }
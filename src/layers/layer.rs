@@ -1,6 +1,24 @@
-use crate::events::EventListener;
+use crate::events::{EventCategory, EventListener};
+use std::any::TypeId;
 
 pub trait Layer: EventListener {
 	fn on_attach(&mut self) {}
 	fn on_detach(&mut self) {}
+	/// The categories of events the layer wants. Events in none of them are
+	/// not dispatched to it. The update and render events of the application
+	/// are always dispatched.
+	fn event_filter(&self) -> EventCategory {
+		EventCategory::ALL
+	}
+	/// Whether the layer wants custom events with a payload of type
+	/// `payload`, e.g. `payload == TypeId::of::<Score>()`. Only consulted
+	/// for events passing `event_filter`.
+	fn wants_custom_event(&self, _payload: TypeId) -> bool {
+		true
+	}
+	/// Whether the layer currently wants text input events. Text input is
+	/// enabled on the window as long as any layer wants it.
+	fn wants_text_input(&self) -> bool {
+		false
+	}
 }
@@ -1,5 +1,5 @@
 use super::Layer;
-use std::collections::{vec_deque::IterMut, VecDeque};
+use std::collections::{vec_deque::Iter, vec_deque::IterMut, VecDeque};
 
 pub struct LayerStack {
 	layers: VecDeque<Box<dyn Layer>>,
@@ -29,11 +29,20 @@ impl LayerStack {
 			layer.on_detach();
 		}
 	}
-	pub fn iter_mut(&mut self) -> IterMut<Box<dyn Layer>> {
+	pub fn iter(&self) -> Iter<'_, Box<dyn Layer>> {
+		self.layers.iter()
+	}
+	pub fn iter_mut(&mut self) -> IterMut<'_, Box<dyn Layer>> {
 		self.layers.iter_mut()
 	}
 }
 
+impl Default for LayerStack {
+	fn default() -> LayerStack {
+		LayerStack::new()
+	}
+}
+
 impl Drop for LayerStack {
 	fn drop(&mut self) {
 		for layer in self.iter_mut() {
@@ -1,10 +1,20 @@
 pub mod application;
+pub mod control;
 pub mod error;
 pub mod events;
 pub mod input;
 pub mod layers;
 pub mod logging;
+pub mod time;
 pub mod vecs;
 pub mod window;
 
 pub type Float = f64;
+
+/// Serializes the tests that create an `Application`, which only exists once
+/// per process.
+#[cfg(test)]
+pub(crate) fn test_lock() -> std::sync::MutexGuard<'static, ()> {
+	static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+	LOCK.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
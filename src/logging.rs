@@ -0,0 +1,191 @@
+use crate::error::Error;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Level {
+	Trace,
+	Debug,
+	Info,
+	Warn,
+	Error,
+}
+
+impl Level {
+	fn name(self) -> &'static str {
+		match self {
+			Level::Trace => "TRACE",
+			Level::Debug => "DEBUG",
+			Level::Info => "INFO",
+			Level::Warn => "WARN",
+			Level::Error => "ERROR",
+		}
+	}
+
+	fn color(self) -> &'static str {
+		match self {
+			Level::Trace => "\x1b[37m",
+			Level::Debug => "\x1b[36m",
+			Level::Info => "\x1b[32m",
+			Level::Warn => "\x1b[33m",
+			Level::Error => "\x1b[31m",
+		}
+	}
+}
+
+impl fmt::Display for Level {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.pad(self.name())
+	}
+}
+
+/// Writes log messages at or above its level to the terminal and to any
+/// number of files.
+///
+/// There are two global loggers: the core logger used by the engine itself
+/// and the client logger meant for applications built on rame. They are
+/// accessed through `core_logger` and `client_logger`, and written to with
+/// the `rame_core_*!` and `rame_*!` macros respectively.
+pub struct Logger {
+	name: &'static str,
+	level: Level,
+	terminal: bool,
+	colored: bool,
+	files: Vec<File>,
+}
+
+impl Logger {
+	pub const fn new(name: &'static str) -> Logger {
+		Logger {
+			name,
+			level: Level::Info,
+			terminal: true,
+			colored: true,
+			files: Vec::new(),
+		}
+	}
+
+	pub fn get_name(&self) -> &str {
+		self.name
+	}
+
+	pub fn get_level(&self) -> Level {
+		self.level
+	}
+
+	pub fn set_level(&mut self, level: Level) {
+		self.level = level;
+	}
+
+	pub fn set_terminal_output(&mut self, enabled: bool) {
+		self.terminal = enabled;
+	}
+
+	/// Only affects terminal output, and only when stdout is a terminal.
+	pub fn set_colored(&mut self, colored: bool) {
+		self.colored = colored;
+	}
+
+	/// Appends all future messages to the file at `path`, creating it if needed.
+	pub fn add_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
+		let file = OpenOptions::new().create(true).append(true).open(path)?;
+		self.files.push(file);
+		Ok(())
+	}
+
+	pub fn is_enabled(&self, level: Level) -> bool {
+		level >= self.level
+	}
+
+	pub fn log(&mut self, level: Level, module: &str, message: &str) {
+		if !self.is_enabled(level) {
+			return;
+		}
+
+		let line = format!("[{}] {} {:<5} {}: {}", timestamp(), self.name, level, module, message);
+
+		if self.terminal {
+			let stdout = io::stdout();
+			let mut stdout = stdout.lock();
+			// Failing to log is not worth crashing over
+			let _ = if self.colored && stdout.is_terminal() {
+				writeln!(stdout, "{}{}\x1b[0m", level.color(), line)
+			} else {
+				writeln!(stdout, "{}", line)
+			};
+		}
+		for file in &mut self.files {
+			let _ = writeln!(file, "{}", line);
+		}
+	}
+
+	pub fn flush(&mut self) {
+		let _ = io::stdout().flush();
+		for file in &mut self.files {
+			let _ = file.flush();
+		}
+	}
+}
+
+/// Formats the current UTC time of day as `HH:MM:SS.mmm`
+fn timestamp() -> String {
+	let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+	let secs = now.as_secs() % (24 * 60 * 60);
+	format!(
+		"{:02}:{:02}:{:02}.{:03}",
+		secs / 3600,
+		secs / 60 % 60,
+		secs % 60,
+		now.subsec_millis()
+	)
+}
+
+static CORE_LOGGER: Mutex<Logger> = Mutex::new(Logger::new("RAME"));
+static CLIENT_LOGGER: Mutex<Logger> = Mutex::new(Logger::new("APP"));
+
+pub fn core_logger() -> MutexGuard<'static, Logger> {
+	CORE_LOGGER.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub fn client_logger() -> MutexGuard<'static, Logger> {
+	CLIENT_LOGGER.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rame_log {
+	($logger:ident, $level:ident, $($arg:tt)+) => {{
+		let level = $crate::logging::Level::$level;
+		if $crate::logging::$logger().is_enabled(level) {
+			// Formatted before locking again, as the arguments may log themselves
+			let message = format!($($arg)+);
+			$crate::logging::$logger().log(level, module_path!(), &message);
+		}
+	}};
+}
+
+#[macro_export]
+macro_rules! rame_core_trace { ($($arg:tt)+) => { $crate::__rame_log!(core_logger, Trace, $($arg)+) }; }
+#[macro_export]
+macro_rules! rame_core_debug { ($($arg:tt)+) => { $crate::__rame_log!(core_logger, Debug, $($arg)+) }; }
+#[macro_export]
+macro_rules! rame_core_info { ($($arg:tt)+) => { $crate::__rame_log!(core_logger, Info, $($arg)+) }; }
+#[macro_export]
+macro_rules! rame_core_warn { ($($arg:tt)+) => { $crate::__rame_log!(core_logger, Warn, $($arg)+) }; }
+#[macro_export]
+macro_rules! rame_core_error { ($($arg:tt)+) => { $crate::__rame_log!(core_logger, Error, $($arg)+) }; }
+
+#[macro_export]
+macro_rules! rame_trace { ($($arg:tt)+) => { $crate::__rame_log!(client_logger, Trace, $($arg)+) }; }
+#[macro_export]
+macro_rules! rame_debug { ($($arg:tt)+) => { $crate::__rame_log!(client_logger, Debug, $($arg)+) }; }
+#[macro_export]
+macro_rules! rame_info { ($($arg:tt)+) => { $crate::__rame_log!(client_logger, Info, $($arg)+) }; }
+#[macro_export]
+macro_rules! rame_warn { ($($arg:tt)+) => { $crate::__rame_log!(client_logger, Warn, $($arg)+) }; }
+#[macro_export]
+macro_rules! rame_error { ($($arg:tt)+) => { $crate::__rame_log!(client_logger, Error, $($arg)+) }; }
@@ -0,0 +1,76 @@
+use crate::Float;
+use std::time::{Duration, Instant};
+
+/// Timing information for a single frame. All times are in seconds.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timestep {
+	/// Time since the previous frame
+	pub delta: Float,
+	/// Time since the first frame
+	pub elapsed: Float,
+	/// Index of the frame, starting at 0
+	pub frame: u64,
+}
+
+impl Timestep {
+	pub fn new(delta: Float, elapsed: Float, frame: u64) -> Timestep {
+		Timestep { delta, elapsed, frame }
+	}
+}
+
+/// Produces one `Timestep` per frame, either by measuring wall clock time
+/// or from externally supplied deltas.
+pub struct FrameClock {
+	last_tick: Option<Instant>,
+	elapsed: Float,
+	frame: u64,
+}
+
+impl FrameClock {
+	pub fn new() -> FrameClock {
+		FrameClock {
+			last_tick: None,
+			elapsed: 0.0,
+			frame: 0,
+		}
+	}
+
+	/// Starts a new frame, measuring the time since the last call. The first
+	/// frame has a delta of zero.
+	pub fn tick(&mut self) -> Timestep {
+		let delta = self.measure();
+		self.advance(delta)
+	}
+
+	/// The wall clock time since the last call to `measure` or `tick`, or
+	/// zero on the first call. Doesn't start a new frame.
+	pub fn measure(&mut self) -> Duration {
+		let now = Instant::now();
+		let delta = match self.last_tick {
+			Some(last_tick) => now - last_tick,
+			None => Duration::from_secs(0),
+		};
+		self.last_tick = Some(now);
+		delta
+	}
+
+	/// Starts a new frame which is `delta` after the previous one.
+	pub fn advance(&mut self, delta: Duration) -> Timestep {
+		self.advance_secs(delta.as_secs_f64() as Float)
+	}
+
+	/// Like `advance`, but without rounding `delta` to whole nanoseconds.
+	pub fn advance_secs(&mut self, delta: Float) -> Timestep {
+		self.elapsed += delta;
+		let timestep = Timestep::new(delta, self.elapsed, self.frame);
+		self.frame += 1;
+		timestep
+	}
+}
+
+impl Default for FrameClock {
+	fn default() -> FrameClock {
+		FrameClock::new()
+	}
+}
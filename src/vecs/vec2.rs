@@ -1,7 +1,9 @@
 use crate::Float;
 use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec2 {
 	pub x: Float,
 	pub y: Float,
@@ -39,7 +41,53 @@ impl Vec2 {
 			y: self.y / mag,
 		}
 	}
-	// TODO: implement ops
+}
+
+impl Add for Vec2 {
+	type Output = Vec2;
+	fn add(self, other: Vec2) -> Vec2 {
+		Vec2::new(self.x + other.x, self.y + other.y)
+	}
+}
+
+impl AddAssign for Vec2 {
+	fn add_assign(&mut self, other: Vec2) {
+		*self = *self + other;
+	}
+}
+
+impl Sub for Vec2 {
+	type Output = Vec2;
+	fn sub(self, other: Vec2) -> Vec2 {
+		Vec2::new(self.x - other.x, self.y - other.y)
+	}
+}
+
+impl SubAssign for Vec2 {
+	fn sub_assign(&mut self, other: Vec2) {
+		*self = *self - other;
+	}
+}
+
+impl Mul<Float> for Vec2 {
+	type Output = Vec2;
+	fn mul(self, scalar: Float) -> Vec2 {
+		Vec2::new(self.x * scalar, self.y * scalar)
+	}
+}
+
+impl Div<Float> for Vec2 {
+	type Output = Vec2;
+	fn div(self, scalar: Float) -> Vec2 {
+		Vec2::new(self.x / scalar, self.y / scalar)
+	}
+}
+
+impl Neg for Vec2 {
+	type Output = Vec2;
+	fn neg(self) -> Vec2 {
+		Vec2::new(-self.x, -self.y)
+	}
 }
 
 impl fmt::Display for Vec2 {
@@ -0,0 +1,59 @@
+use super::{CursorIcon, WindowIcon, WindowMode};
+use crate::error::Error;
+use crate::events::EventQueue;
+use crate::vecs::Vec2;
+use crate::Float;
+use std::time::Duration;
+
+/// The platform specific half of a `Window`.
+///
+/// A backend owns whatever surface and context the window renders to and
+/// translates the platform's events into rame events. Backends without a
+/// surface can ignore the window setters, which do nothing by default.
+pub trait Backend {
+	fn set_vsync(&mut self, vsync: bool) -> Result<(), Error>;
+	fn set_title(&mut self, _title: &str) {}
+	fn set_size(&mut self, _width: u32, _height: u32) {}
+	fn set_position(&mut self, _x: i32, _y: i32) {}
+	fn set_resizable(&mut self, _resizable: bool) {}
+	fn set_mode(&mut self, _mode: WindowMode) {}
+	fn set_min_size(&mut self, _size: Option<(u32, u32)>) {}
+	fn set_max_size(&mut self, _size: Option<(u32, u32)>) {}
+	fn set_icon(&mut self, _icon: Option<&WindowIcon>) {}
+	fn set_cursor_icon(&mut self, _icon: CursorIcon) {}
+	fn set_cursor_visible(&mut self, _visible: bool) {}
+	fn set_cursor_grabbed(&mut self, _grabbed: bool) -> Result<(), Error> {
+		Ok(())
+	}
+	/// Moves the cursor to `position` within the window, in the same
+	/// coordinates as mouse moved events.
+	fn set_cursor_position(&mut self, _position: Vec2) -> Result<(), Error> {
+		Ok(())
+	}
+	/// Whether typed text should be reported through text input events.
+	/// Backends that can report IME composition should do so through
+	/// `TextCompositionEvent`s while enabled.
+	fn set_text_input(&mut self, _enabled: bool) {}
+	/// The number of physical pixels per logical pixel when the window was
+	/// created. Later changes are reported through `DpiChangedEvent`s.
+	fn get_scale_factor(&self) -> Float {
+		1.0
+	}
+	/// Pushes every event that happened since the last call onto `event_queue`.
+	fn poll_events(&mut self, event_queue: &mut EventQueue);
+	/// Resizes the framebuffer and the viewport to `width` by `height`
+	/// physical pixels. Called with the window's context current.
+	fn resize_framebuffer(&mut self, _width: u32, _height: u32) {}
+	/// Makes the window's context the one GL calls go to on this thread.
+	fn make_current(&mut self) -> Result<(), Error> {
+		Ok(())
+	}
+	fn clear_color(&mut self, r: Float, g: Float, b: Float);
+	fn clear_screen(&mut self);
+	fn swap_buffers(&mut self) -> Result<(), Error>;
+	/// The duration of the coming frame, if the backend dictates it. When
+	/// `None` is returned the wall clock is used instead.
+	fn frame_delta(&mut self) -> Option<Duration> {
+		None
+	}
+}
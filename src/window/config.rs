@@ -0,0 +1,272 @@
+use super::CursorIcon;
+use crate::error::{Error, ErrorKind};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum WindowMode {
+	Windowed,
+	/// Windowed, but without title bar and borders
+	Borderless,
+	/// Covering the whole monitor the window is on
+	Fullscreen,
+}
+
+/// An image to show in the title bar and task bar, as 8 bit RGBA pixels
+/// row by row from the top left.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct WindowIcon {
+	rgba: Vec<u8>,
+	width: u32,
+	height: u32,
+}
+
+impl WindowIcon {
+	pub fn from_rgba(rgba: Vec<u8>, width: u32, height: u32) -> Result<WindowIcon, Error> {
+		if rgba.len() as u64 != u64::from(width) * u64::from(height) * 4 {
+			let message = format!("{} bytes is not a {}x{} RGBA icon", rgba.len(), width, height);
+			return Err(Error::with_message(ErrorKind::Asset, message));
+		}
+		Ok(WindowIcon { rgba, width, height })
+	}
+
+	pub fn get_rgba(&self) -> &[u8] {
+		&self.rgba
+	}
+
+	pub fn get_width(&self) -> u32 {
+		self.width
+	}
+
+	pub fn get_height(&self) -> u32 {
+		self.height
+	}
+}
+
+/// The settings of a window, used to create one and kept up to date as the
+/// window changes.
+///
+/// Built up from `WindowConfig::new` with the `with_*` methods, e.g.
+/// `WindowConfig::new("Sandbox", 1280, 720).with_vsync(false)`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct WindowConfig {
+	title: String,
+	width: u32,
+	height: u32,
+	vsync: bool,
+	position: Option<(i32, i32)>,
+	resizable: bool,
+	mode: WindowMode,
+	min_size: Option<(u32, u32)>,
+	max_size: Option<(u32, u32)>,
+	icon: Option<WindowIcon>,
+	shared_context: bool,
+	cursor_icon: CursorIcon,
+	cursor_visible: bool,
+	cursor_grabbed: bool,
+}
+
+impl WindowConfig {
+	/// A resizable window with vsync, placed wherever the platform prefers
+	pub fn new(title: &str, width: u32, height: u32) -> WindowConfig {
+		WindowConfig {
+			title: title.to_string(),
+			width,
+			height,
+			vsync: true,
+			position: None,
+			resizable: true,
+			mode: WindowMode::Windowed,
+			min_size: None,
+			max_size: None,
+			icon: None,
+			shared_context: true,
+			cursor_icon: CursorIcon::Default,
+			cursor_visible: true,
+			cursor_grabbed: false,
+		}
+	}
+
+	pub fn with_title(mut self, title: &str) -> WindowConfig {
+		self.title = title.to_string();
+		self
+	}
+
+	pub fn with_size(mut self, width: u32, height: u32) -> WindowConfig {
+		self.width = width;
+		self.height = height;
+		self
+	}
+
+	pub fn with_vsync(mut self, vsync: bool) -> WindowConfig {
+		self.vsync = vsync;
+		self
+	}
+
+	/// Places the top left corner of the window at `x`, `y` on the desktop.
+	pub fn with_position(mut self, x: i32, y: i32) -> WindowConfig {
+		self.position = Some((x, y));
+		self
+	}
+
+	pub fn with_resizable(mut self, resizable: bool) -> WindowConfig {
+		self.resizable = resizable;
+		self
+	}
+
+	pub fn with_mode(mut self, mode: WindowMode) -> WindowConfig {
+		self.mode = mode;
+		self
+	}
+
+	pub fn with_min_size(mut self, width: u32, height: u32) -> WindowConfig {
+		self.min_size = Some((width, height));
+		self
+	}
+
+	pub fn with_max_size(mut self, width: u32, height: u32) -> WindowConfig {
+		self.max_size = Some((width, height));
+		self
+	}
+
+	pub fn with_icon(mut self, icon: WindowIcon) -> WindowConfig {
+		self.icon = Some(icon);
+		self
+	}
+
+	/// Whether the window's GL context shares objects like textures and
+	/// buffers with the other windows of the application. Enabled by default.
+	pub fn with_shared_context(mut self, shared: bool) -> WindowConfig {
+		self.shared_context = shared;
+		self
+	}
+
+	pub fn with_cursor_icon(mut self, icon: CursorIcon) -> WindowConfig {
+		self.cursor_icon = icon;
+		self
+	}
+
+	/// Whether the cursor is shown while over the window
+	pub fn with_cursor_visible(mut self, visible: bool) -> WindowConfig {
+		self.cursor_visible = visible;
+		self
+	}
+
+	/// Whether the cursor is kept from leaving the window while it's focused
+	pub fn with_cursor_grabbed(mut self, grabbed: bool) -> WindowConfig {
+		self.cursor_grabbed = grabbed;
+		self
+	}
+
+	pub fn get_title(&self) -> &str {
+		&self.title
+	}
+
+	pub fn get_width(&self) -> u32 {
+		self.width
+	}
+
+	pub fn get_height(&self) -> u32 {
+		self.height
+	}
+
+	pub fn get_vsync(&self) -> bool {
+		self.vsync
+	}
+
+	/// `None` until the window has been placed explicitly or moved
+	pub fn get_position(&self) -> Option<(i32, i32)> {
+		self.position
+	}
+
+	pub fn is_resizable(&self) -> bool {
+		self.resizable
+	}
+
+	pub fn get_mode(&self) -> WindowMode {
+		self.mode
+	}
+
+	pub fn get_min_size(&self) -> Option<(u32, u32)> {
+		self.min_size
+	}
+
+	pub fn get_max_size(&self) -> Option<(u32, u32)> {
+		self.max_size
+	}
+
+	pub fn get_icon(&self) -> Option<&WindowIcon> {
+		self.icon.as_ref()
+	}
+
+	pub fn is_context_shared(&self) -> bool {
+		self.shared_context
+	}
+
+	pub fn get_cursor_icon(&self) -> CursorIcon {
+		self.cursor_icon
+	}
+
+	pub fn is_cursor_visible(&self) -> bool {
+		self.cursor_visible
+	}
+
+	pub fn is_cursor_grabbed(&self) -> bool {
+		self.cursor_grabbed
+	}
+
+	// Window keeps its config in sync through these
+
+	pub(crate) fn set_title(&mut self, title: &str) {
+		self.title = title.to_string();
+	}
+
+	pub(crate) fn set_size(&mut self, width: u32, height: u32) {
+		self.width = width;
+		self.height = height;
+	}
+
+	pub(crate) fn set_vsync(&mut self, vsync: bool) {
+		self.vsync = vsync;
+	}
+
+	pub(crate) fn set_position(&mut self, x: i32, y: i32) {
+		self.position = Some((x, y));
+	}
+
+	pub(crate) fn set_resizable(&mut self, resizable: bool) {
+		self.resizable = resizable;
+	}
+
+	pub(crate) fn set_mode(&mut self, mode: WindowMode) {
+		self.mode = mode;
+	}
+
+	pub(crate) fn set_min_size(&mut self, size: Option<(u32, u32)>) {
+		self.min_size = size;
+	}
+
+	pub(crate) fn set_max_size(&mut self, size: Option<(u32, u32)>) {
+		self.max_size = size;
+	}
+
+	pub(crate) fn set_icon(&mut self, icon: Option<WindowIcon>) {
+		self.icon = icon;
+	}
+
+	pub(crate) fn set_cursor_icon(&mut self, icon: CursorIcon) {
+		self.cursor_icon = icon;
+	}
+
+	pub(crate) fn set_cursor_visible(&mut self, visible: bool) {
+		self.cursor_visible = visible;
+	}
+
+	pub(crate) fn set_cursor_grabbed(&mut self, grabbed: bool) {
+		self.cursor_grabbed = grabbed;
+	}
+}
+
+impl Default for WindowConfig {
+	fn default() -> WindowConfig {
+		WindowConfig::new("rame", 1280, 720)
+	}
+}
@@ -0,0 +1,42 @@
+/// The system cursors a window can show, named after their CSS equivalents.
+/// Platforms without a matching cursor show the closest one they have.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum CursorIcon {
+	#[default]
+	Default,
+	Crosshair,
+	Hand,
+	Arrow,
+	Move,
+	Text,
+	Wait,
+	Help,
+	/// Busy in the background, but still interactable
+	Progress,
+	NotAllowed,
+	ContextMenu,
+	Cell,
+	VerticalText,
+	Alias,
+	Copy,
+	NoDrop,
+	Grab,
+	Grabbing,
+	AllScroll,
+	ZoomIn,
+	ZoomOut,
+	EResize,
+	NResize,
+	NeResize,
+	NwResize,
+	SResize,
+	SeResize,
+	SwResize,
+	WResize,
+	EwResize,
+	NsResize,
+	NeswResize,
+	NwseResize,
+	ColResize,
+	RowResize,
+}
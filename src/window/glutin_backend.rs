@@ -0,0 +1,678 @@
+use super::{swap_interval, Backend, CursorIcon, WindowConfig, WindowIcon, WindowMode};
+use crate::error::{Error, ErrorKind};
+use crate::events::{self, EventQueue};
+use crate::input::{Button, Key, Modifiers};
+use crate::vecs::Vec2;
+use crate::Float;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+/// The events loop of the current thread, shared by all of its windows as
+/// some platforms only allow one.
+struct SharedEvents {
+	events_loop: glutin::EventsLoop,
+	// Polled events by window, waiting for that window's backend to take them
+	pending: HashMap<glutin::WindowId, Vec<glutin::Event>>,
+	// The context that new windows share their GL objects with
+	shared_context: Weak<glutin::GlWindow>,
+}
+
+impl SharedEvents {
+	/// The events loop of the current thread, created if no window is open
+	fn get() -> Rc<RefCell<SharedEvents>> {
+		thread_local! {
+			static SHARED_EVENTS: RefCell<Weak<RefCell<SharedEvents>>> = const { RefCell::new(Weak::new()) };
+		}
+		SHARED_EVENTS.with(|shared| {
+			let mut shared = shared.borrow_mut();
+			match shared.upgrade() {
+				Some(events) => events,
+				None => {
+					let events = Rc::new(RefCell::new(SharedEvents {
+						events_loop: glutin::EventsLoop::new(),
+						pending: HashMap::new(),
+						shared_context: Weak::new(),
+					}));
+					*shared = Rc::downgrade(&events);
+					events
+				}
+			}
+		})
+	}
+
+	fn take_events(&mut self, window_id: glutin::WindowId) -> Vec<glutin::Event> {
+		let pending = &mut self.pending;
+		self.events_loop.poll_events(|event| match event {
+			glutin::Event::WindowEvent { window_id, .. } => {
+				// Events of closed windows are dropped
+				if let Some(events) = pending.get_mut(&window_id) {
+					events.push(event);
+				}
+			}
+			// Device events aren't tied to a window, every window decides
+			// for itself whether it wants them
+			glutin::Event::DeviceEvent {
+				event: glutin::DeviceEvent::MouseMotion { .. },
+				..
+			} => {
+				for events in pending.values_mut() {
+					events.push(event.clone());
+				}
+			}
+			_ => {}
+		});
+		match self.pending.get_mut(&window_id) {
+			Some(events) => std::mem::take(events),
+			None => Vec::new(),
+		}
+	}
+}
+
+/// A backend rendering to a glutin window.
+///
+/// glutin doesn't report IME composition, so text composed with an IME only
+/// arrives once committed, as a `TextInputEvent`.
+pub struct GlutinBackend {
+	glutin_window: Rc<glutin::GlWindow>,
+	shared_events: Rc<RefCell<SharedEvents>>,
+	// Kept up to date so the window can be recreated with the same settings
+	config: WindowConfig,
+	// The keys currently held by scancode, for telling OS key repeats apart
+	// from actual presses
+	held_keys: HashMap<u32, Key>,
+	// Keys to report as released on the next poll
+	released_keys: Vec<Key>,
+	text_input: bool,
+	// Every window receives the raw mouse motion, which only the focused
+	// one reports. Windows start out unfocused until told otherwise, so a
+	// newly opened window doesn't double the motion of the focused one.
+	focused: bool,
+	scale_factor: Float,
+	// glutin doesn't report minimizing directly. Windows reports it as a
+	// resize to 0x0, other platforms not at all.
+	minimized: bool,
+}
+
+impl GlutinBackend {
+	/// Windows created on the same thread share an events loop, and unless
+	/// disabled in `config` their GL contexts share objects like textures.
+	pub fn new(config: &WindowConfig) -> Result<GlutinBackend, Error> {
+		let shared_events = SharedEvents::get();
+		let glutin_window = {
+			let mut shared = shared_events.borrow_mut();
+			let shared_context = match config.is_context_shared() {
+				true => shared.shared_context.upgrade(),
+				false => None,
+			};
+			let glutin_window = Rc::new(build_window(config, &shared.events_loop, shared_context.as_deref())?);
+			if shared_context.is_none() && config.is_context_shared() {
+				shared.shared_context = Rc::downgrade(&glutin_window);
+			}
+			shared.pending.insert(glutin_window.id(), Vec::new());
+			glutin_window
+		};
+		crate::rame_core_info!(
+			"created window \"{}\" ({}x{}, vsync {})",
+			config.get_title(),
+			config.get_width(),
+			config.get_height(),
+			config.get_vsync()
+		);
+
+		let scale_factor = glutin_window.get_hidpi_factor();
+		Ok(GlutinBackend {
+			glutin_window,
+			shared_events,
+			config: config.clone(),
+			held_keys: HashMap::new(),
+			released_keys: Vec::new(),
+			text_input: false,
+			focused: false,
+			scale_factor,
+			minimized: false,
+		})
+	}
+
+	fn handle_event(&mut self, event: glutin::WindowEvent, event_queue: &mut EventQueue, text: &mut String) {
+		match event {
+			glutin::WindowEvent::CloseRequested => {
+				event_queue.push(events::WindowClosedEvent::new());
+			}
+			glutin::WindowEvent::Resized(size) => {
+				let (width, height) = (size.width.round() as u32, size.height.round() as u32);
+				self.config.set_size(width, height);
+				let minimized = width == 0 && height == 0;
+				if minimized != self.minimized {
+					self.minimized = minimized;
+					event_queue.push(events::WindowMinimizedEvent::new(minimized));
+				}
+				event_queue.push(events::WindowResizedEvent::new(width, height, self.scale_factor));
+			}
+			glutin::WindowEvent::Moved(position) => {
+				let (x, y): (i32, i32) = position.into();
+				self.config.set_position(x, y);
+				event_queue.push(events::WindowMovedEvent::new(x, y));
+			}
+			glutin::WindowEvent::HiDpiFactorChanged(scale_factor) => {
+				self.scale_factor = scale_factor;
+				event_queue.push(events::DpiChangedEvent::new(scale_factor));
+			}
+			glutin::WindowEvent::DroppedFile(path) => {
+				event_queue.push(events::FileDroppedEvent::new(path));
+			}
+			glutin::WindowEvent::HoveredFile(path) => {
+				event_queue.push(events::FileHoveredEvent::new(path));
+			}
+			glutin::WindowEvent::HoveredFileCancelled => {
+				event_queue.push(events::FileHoverCancelledEvent::new());
+			}
+			glutin::WindowEvent::CursorEntered { .. } => {
+				event_queue.push(events::CursorEnteredEvent::new());
+			}
+			glutin::WindowEvent::CursorLeft { .. } => {
+				event_queue.push(events::CursorLeftEvent::new());
+			}
+			glutin::WindowEvent::KeyboardInput { input, .. } => {
+				event_queue.push(match input.state {
+					glutin::ElementState::Pressed => {
+						let key = convert_key_event(input);
+						let repeat = self.held_keys.insert(input.scancode, key).is_some();
+						events::KeyPressedEvent::new(key, repeat)
+					}
+					glutin::ElementState::Released => {
+						self.held_keys.remove(&input.scancode);
+						events::KeyReleasedEvent::new(convert_key_event(input))
+					}
+				});
+			}
+			glutin::WindowEvent::Focused(focused) => {
+				// Keys released while unfocused are never reported,
+				// so consider them released right away
+				self.focused = focused;
+				if !focused {
+					for (_, key) in self.held_keys.drain() {
+						event_queue.push(events::KeyReleasedEvent::new(key));
+					}
+				}
+				event_queue.push(events::WindowFocusEvent::new(focused));
+			}
+			glutin::WindowEvent::ReceivedCharacter(c) => {
+				event_queue.push(events::CharWrittenEvent::new(c));
+				if !c.is_control() {
+					text.push(c);
+				}
+			}
+			glutin::WindowEvent::MouseInput { state, button, .. } => {
+				event_queue.push(match state {
+					glutin::ElementState::Pressed => events::MousePressedEvent::new(convert_mouse_button(button)),
+					glutin::ElementState::Released => events::MouseReleasedEvent::new(convert_mouse_button(button)),
+				});
+			}
+			glutin::WindowEvent::MouseWheel { delta, .. } => {
+				event_queue.push(events::MouseScrolledEvent::new(match delta {
+					glutin::MouseScrollDelta::LineDelta(x, y) => Vec2::new(x.into(), y.into()),
+					glutin::MouseScrollDelta::PixelDelta(d) => Vec2::new(d.x as Float, d.y as Float),
+					// NOTE: Perhaps scrolling speed will be fucked
+					// depending on which eventis received here.
+				}));
+			}
+			glutin::WindowEvent::CursorMoved { position: pos, .. } => {
+				let position = Vec2::new(pos.x as Float, pos.y as Float);
+				event_queue.push(events::MouseMovedEvent::new(position, self.scale_factor));
+			}
+			_ => {}
+		}
+	}
+}
+
+impl Drop for GlutinBackend {
+	fn drop(&mut self) {
+		self.shared_events.borrow_mut().pending.remove(&self.glutin_window.id());
+	}
+}
+
+/// Creates a window and makes its context current. If `shared` is given,
+/// the new context shares its GL objects with that window's context.
+fn build_window(
+	config: &WindowConfig,
+	events_loop: &glutin::EventsLoop,
+	shared: Option<&glutin::GlWindow>,
+) -> Result<glutin::GlWindow, Error> {
+	let (width, height) = (config.get_width(), config.get_height());
+	let mut window_builder = glutin::WindowBuilder::new()
+		.with_dimensions(glutin::dpi::LogicalSize::new(width.into(), height.into()))
+		.with_title(config.get_title())
+		.with_resizable(config.is_resizable())
+		.with_decorations(config.get_mode() == WindowMode::Windowed)
+		.with_window_icon(config.get_icon().and_then(convert_icon));
+	if let Some((width, height)) = config.get_min_size() {
+		window_builder = window_builder.with_min_dimensions(glutin::dpi::LogicalSize::new(width.into(), height.into()));
+	}
+	if let Some((width, height)) = config.get_max_size() {
+		window_builder = window_builder.with_max_dimensions(glutin::dpi::LogicalSize::new(width.into(), height.into()));
+	}
+	if config.get_mode() == WindowMode::Fullscreen {
+		window_builder = window_builder.with_fullscreen(Some(events_loop.get_primary_monitor()));
+	}
+	let context_builder = glutin::ContextBuilder::new().with_vsync(config.get_vsync());
+
+	let result = match shared {
+		// Safe as the shared context also belongs to a `GlWindow`, which
+		// glutin allows sharing with on every platform
+		Some(shared) => unsafe {
+			let context_builder = context_builder.with_shared_lists(shared.context());
+			glutin::GlWindow::new_shared(window_builder, context_builder, events_loop)
+		},
+		None => glutin::GlWindow::new(window_builder, context_builder, events_loop),
+	};
+	let glutin_window = match result {
+		Ok(w) => w,
+		Err(err) => {
+			crate::rame_core_error!("failed to create window \"{}\": {}", config.get_title(), err);
+			// glutin's errors may wrap platform errors that aren't `Sync`
+			return Err(Error::with_message(ErrorKind::WindowCreation, err.to_string()));
+		}
+	};
+
+	if let Some((x, y)) = config.get_position() {
+		glutin_window.set_position(glutin::dpi::LogicalPosition::new(x.into(), y.into()));
+	}
+
+	glutin_window.set_cursor(convert_cursor_icon(config.get_cursor_icon()));
+	glutin_window.hide_cursor(!config.is_cursor_visible());
+	if let Err(err) = glutin_window.grab_cursor(config.is_cursor_grabbed()) {
+		crate::rame_core_warn!("failed to grab cursor: {}", err);
+	}
+
+	unsafe {
+		use glutin::GlContext;
+		glutin_window.make_current().map_err(convert_context_error)?;
+		gl::load_with(|s| glutin_window.get_proc_address(s) as _);
+	}
+
+	Ok(glutin_window)
+}
+
+impl Backend for GlutinBackend {
+	/// Sets the swap interval through the platform's swap control extension
+	/// if there is one. Otherwise the window is recreated, with a context
+	/// sharing the old one's objects.
+	fn set_vsync(&mut self, vsync: bool) -> Result<(), Error> {
+		self.make_current()?;
+		if swap_interval::set_swap_interval(&self.glutin_window, vsync as i32) {
+			self.config.set_vsync(vsync);
+			return Ok(());
+		}
+
+		let mut config = self.config.clone();
+		config.set_vsync(vsync);
+		// Keep the new window where the old one is, instead of wherever the
+		// platform places new windows
+		if let Some(position) = self.glutin_window.get_position() {
+			let (x, y): (i32, i32) = position.into();
+			config.set_position(x, y);
+		}
+		let mut shared = self.shared_events.borrow_mut();
+		let glutin_window = Rc::new(build_window(&config, &shared.events_loop, Some(&self.glutin_window))?);
+		if shared.shared_context.upgrade().is_some_and(|context| Rc::ptr_eq(&context, &self.glutin_window)) {
+			shared.shared_context = Rc::downgrade(&glutin_window);
+		}
+		shared.pending.remove(&self.glutin_window.id());
+		shared.pending.insert(glutin_window.id(), Vec::new());
+		drop(shared);
+		self.glutin_window = glutin_window;
+		self.config = config;
+		// The new window never reports keys held in the old one as released
+		self.released_keys.extend(self.held_keys.drain().map(|(_, key)| key));
+		crate::rame_core_info!("recreated window \"{}\" with vsync {}", self.config.get_title(), vsync);
+		Ok(())
+	}
+
+	fn set_title(&mut self, title: &str) {
+		self.config.set_title(title);
+		self.glutin_window.set_title(title);
+	}
+
+	fn set_size(&mut self, width: u32, height: u32) {
+		self.config.set_size(width, height);
+		self.glutin_window
+			.set_inner_size(glutin::dpi::LogicalSize::new(width.into(), height.into()));
+	}
+
+	fn set_position(&mut self, x: i32, y: i32) {
+		self.config.set_position(x, y);
+		self.glutin_window
+			.set_position(glutin::dpi::LogicalPosition::new(x.into(), y.into()));
+	}
+
+	fn set_resizable(&mut self, resizable: bool) {
+		self.config.set_resizable(resizable);
+		self.glutin_window.set_resizable(resizable);
+	}
+
+	fn set_mode(&mut self, mode: WindowMode) {
+		self.config.set_mode(mode);
+		match mode {
+			WindowMode::Windowed | WindowMode::Borderless => {
+				self.glutin_window.set_fullscreen(None);
+				self.glutin_window.set_decorations(mode == WindowMode::Windowed);
+			}
+			WindowMode::Fullscreen => {
+				let monitor = self.glutin_window.get_current_monitor();
+				self.glutin_window.set_fullscreen(Some(monitor));
+			}
+		}
+	}
+
+	fn set_min_size(&mut self, size: Option<(u32, u32)>) {
+		self.config.set_min_size(size);
+		let size = size.map(|(width, height)| glutin::dpi::LogicalSize::new(width.into(), height.into()));
+		self.glutin_window.set_min_dimensions(size);
+	}
+
+	fn set_max_size(&mut self, size: Option<(u32, u32)>) {
+		self.config.set_max_size(size);
+		let size = size.map(|(width, height)| glutin::dpi::LogicalSize::new(width.into(), height.into()));
+		self.glutin_window.set_max_dimensions(size);
+	}
+
+	fn set_icon(&mut self, icon: Option<&WindowIcon>) {
+		self.config.set_icon(icon.cloned());
+		self.glutin_window.set_window_icon(icon.and_then(convert_icon));
+	}
+
+	fn set_cursor_icon(&mut self, icon: CursorIcon) {
+		self.config.set_cursor_icon(icon);
+		self.glutin_window.set_cursor(convert_cursor_icon(icon));
+	}
+
+	fn set_cursor_visible(&mut self, visible: bool) {
+		self.config.set_cursor_visible(visible);
+		self.glutin_window.hide_cursor(!visible);
+	}
+
+	fn set_cursor_grabbed(&mut self, grabbed: bool) -> Result<(), Error> {
+		if let Err(err) = self.glutin_window.grab_cursor(grabbed) {
+			return Err(Error::with_message(ErrorKind::Other, format!("failed to grab cursor: {}", err)));
+		}
+		self.config.set_cursor_grabbed(grabbed);
+		Ok(())
+	}
+
+	fn set_cursor_position(&mut self, position: Vec2) -> Result<(), Error> {
+		let position = glutin::dpi::LogicalPosition::new(position.x, position.y);
+		match self.glutin_window.set_cursor_position(position) {
+			Ok(()) => Ok(()),
+			Err(err) => Err(Error::with_message(ErrorKind::Other, format!("failed to move cursor: {}", err))),
+		}
+	}
+
+	fn set_text_input(&mut self, enabled: bool) {
+		self.text_input = enabled;
+	}
+
+	fn get_scale_factor(&self) -> Float {
+		self.scale_factor
+	}
+
+	fn poll_events(&mut self, event_queue: &mut EventQueue) {
+		let events = self.shared_events.borrow_mut().take_events(self.glutin_window.id());
+		for key in self.released_keys.drain(..) {
+			event_queue.push(events::KeyReleasedEvent::new(key));
+		}
+		let mut text = String::new();
+		for event in events {
+			match event {
+				glutin::Event::WindowEvent { event, .. } => self.handle_event(event, event_queue, &mut text),
+				glutin::Event::DeviceEvent {
+					event: glutin::DeviceEvent::MouseMotion { delta: (x, y) },
+					..
+				} if self.focused => {
+					event_queue.push(events::MouseMotionEvent::new(Vec2::new(x, y)));
+				}
+				_ => {}
+			}
+		}
+
+		// Characters typed in the same frame are delivered together
+		if self.text_input && !text.is_empty() {
+			event_queue.push(events::TextInputEvent::new(text));
+		}
+	}
+
+	fn make_current(&mut self) -> Result<(), Error> {
+		unsafe {
+			use glutin::GlContext;
+			self.glutin_window.make_current().map_err(convert_context_error)
+		}
+	}
+
+	fn resize_framebuffer(&mut self, width: u32, height: u32) {
+		self.glutin_window
+			.resize(glutin::dpi::PhysicalSize::new(width.into(), height.into()));
+		unsafe {
+			gl::Viewport(0, 0, width as _, height as _);
+		}
+	}
+
+	fn clear_color(&mut self, r: Float, g: Float, b: Float) {
+		unsafe {
+			gl::ClearColor(r as _, g as _, b as _, 1.0);
+		}
+	}
+
+	fn clear_screen(&mut self) {
+		unsafe {
+			gl::Clear(gl::COLOR_BUFFER_BIT);
+		}
+	}
+
+	fn swap_buffers(&mut self) -> Result<(), Error> {
+		self.glutin_window.swap_buffers().map_err(convert_context_error)
+	}
+}
+
+fn convert_context_error(err: glutin::ContextError) -> Error {
+	match err {
+		glutin::ContextError::ContextLost => Error::new(ErrorKind::ContextLost),
+		glutin::ContextError::IoError(err) => Error::from(err),
+		glutin::ContextError::OsError(message) => Error::with_message(ErrorKind::Context, message),
+	}
+}
+
+fn convert_icon(icon: &WindowIcon) -> Option<glutin::Icon> {
+	match glutin::Icon::from_rgba(icon.get_rgba().to_vec(), icon.get_width(), icon.get_height()) {
+		Ok(icon) => Some(icon),
+		Err(err) => {
+			crate::rame_core_warn!("ignoring invalid window icon: {}", err);
+			None
+		}
+	}
+}
+
+fn convert_cursor_icon(icon: CursorIcon) -> glutin::MouseCursor {
+	match icon {
+		CursorIcon::Default => glutin::MouseCursor::Default,
+		CursorIcon::Crosshair => glutin::MouseCursor::Crosshair,
+		CursorIcon::Hand => glutin::MouseCursor::Hand,
+		CursorIcon::Arrow => glutin::MouseCursor::Arrow,
+		CursorIcon::Move => glutin::MouseCursor::Move,
+		CursorIcon::Text => glutin::MouseCursor::Text,
+		CursorIcon::Wait => glutin::MouseCursor::Wait,
+		CursorIcon::Help => glutin::MouseCursor::Help,
+		CursorIcon::Progress => glutin::MouseCursor::Progress,
+		CursorIcon::NotAllowed => glutin::MouseCursor::NotAllowed,
+		CursorIcon::ContextMenu => glutin::MouseCursor::ContextMenu,
+		CursorIcon::Cell => glutin::MouseCursor::Cell,
+		CursorIcon::VerticalText => glutin::MouseCursor::VerticalText,
+		CursorIcon::Alias => glutin::MouseCursor::Alias,
+		CursorIcon::Copy => glutin::MouseCursor::Copy,
+		CursorIcon::NoDrop => glutin::MouseCursor::NoDrop,
+		CursorIcon::Grab => glutin::MouseCursor::Grab,
+		CursorIcon::Grabbing => glutin::MouseCursor::Grabbing,
+		CursorIcon::AllScroll => glutin::MouseCursor::AllScroll,
+		CursorIcon::ZoomIn => glutin::MouseCursor::ZoomIn,
+		CursorIcon::ZoomOut => glutin::MouseCursor::ZoomOut,
+		CursorIcon::EResize => glutin::MouseCursor::EResize,
+		CursorIcon::NResize => glutin::MouseCursor::NResize,
+		CursorIcon::NeResize => glutin::MouseCursor::NeResize,
+		CursorIcon::NwResize => glutin::MouseCursor::NwResize,
+		CursorIcon::SResize => glutin::MouseCursor::SResize,
+		CursorIcon::SeResize => glutin::MouseCursor::SeResize,
+		CursorIcon::SwResize => glutin::MouseCursor::SwResize,
+		CursorIcon::WResize => glutin::MouseCursor::WResize,
+		CursorIcon::EwResize => glutin::MouseCursor::EwResize,
+		CursorIcon::NsResize => glutin::MouseCursor::NsResize,
+		CursorIcon::NeswResize => glutin::MouseCursor::NeswResize,
+		CursorIcon::NwseResize => glutin::MouseCursor::NwseResize,
+		CursorIcon::ColResize => glutin::MouseCursor::ColResize,
+		CursorIcon::RowResize => glutin::MouseCursor::RowResize,
+	}
+}
+
+fn convert_mouse_button(button: glutin::MouseButton) -> Button {
+	match button {
+		glutin::MouseButton::Left => Button::MouseLeft,
+		glutin::MouseButton::Middle => Button::MouseMiddle,
+		glutin::MouseButton::Right => Button::MouseRight,
+		glutin::MouseButton::Other(4) => Button::Mouse4,
+		glutin::MouseButton::Other(5) => Button::Mouse5,
+		glutin::MouseButton::Other(6) => Button::Mouse6,
+		glutin::MouseButton::Other(7) => Button::Mouse7,
+		glutin::MouseButton::Other(8) => Button::Mouse8,
+		glutin::MouseButton::Other(_) => Button::Unknown,
+	}
+}
+
+fn convert_key_event(input: glutin::KeyboardInput) -> Key {
+	let mut modifiers = Modifiers::NONE;
+	if input.modifiers.shift {
+		modifiers |= Modifiers::SHIFT;
+	}
+	if input.modifiers.ctrl {
+		modifiers |= Modifiers::CTRL;
+	}
+	if input.modifiers.alt {
+		modifiers |= Modifiers::ALT;
+	}
+	if input.modifiers.logo {
+		modifiers |= Modifiers::SUPER;
+	}
+	let button = match input.virtual_keycode {
+		Some(key) => convert_virtual_keycode(key),
+		None => Button::Unknown,
+	};
+	Key::new(button, Some(input.scancode), modifiers)
+}
+
+fn convert_virtual_keycode(key: glutin::VirtualKeyCode) -> Button {
+	use crate::input::Button::*;
+	match key {
+		glutin::VirtualKeyCode::Space => Space,
+		glutin::VirtualKeyCode::Apostrophe => Apostrophe,
+		glutin::VirtualKeyCode::Comma => Comma,
+		glutin::VirtualKeyCode::Period => Period,
+		glutin::VirtualKeyCode::Slash => Slash,
+		glutin::VirtualKeyCode::Grave => Tilde,
+		glutin::VirtualKeyCode::Key1 => Num1,
+		glutin::VirtualKeyCode::Key2 => Num2,
+		glutin::VirtualKeyCode::Key3 => Num3,
+		glutin::VirtualKeyCode::Key4 => Num4,
+		glutin::VirtualKeyCode::Key5 => Num5,
+		glutin::VirtualKeyCode::Key6 => Num6,
+		glutin::VirtualKeyCode::Key7 => Num7,
+		glutin::VirtualKeyCode::Key8 => Num8,
+		glutin::VirtualKeyCode::Key9 => Num9,
+		glutin::VirtualKeyCode::Key0 => Num0,
+		glutin::VirtualKeyCode::Subtract => Minus,
+		glutin::VirtualKeyCode::Equals => Equals,
+		glutin::VirtualKeyCode::A => A,
+		glutin::VirtualKeyCode::B => B,
+		glutin::VirtualKeyCode::C => C,
+		glutin::VirtualKeyCode::D => D,
+		glutin::VirtualKeyCode::E => E,
+		glutin::VirtualKeyCode::F => F,
+		glutin::VirtualKeyCode::G => G,
+		glutin::VirtualKeyCode::H => H,
+		glutin::VirtualKeyCode::I => I,
+		glutin::VirtualKeyCode::J => J,
+		glutin::VirtualKeyCode::K => K,
+		glutin::VirtualKeyCode::L => L,
+		glutin::VirtualKeyCode::M => M,
+		glutin::VirtualKeyCode::N => N,
+		glutin::VirtualKeyCode::O => O,
+		glutin::VirtualKeyCode::P => P,
+		glutin::VirtualKeyCode::Q => Q,
+		glutin::VirtualKeyCode::R => R,
+		glutin::VirtualKeyCode::S => S,
+		glutin::VirtualKeyCode::T => T,
+		glutin::VirtualKeyCode::U => U,
+		glutin::VirtualKeyCode::V => V,
+		glutin::VirtualKeyCode::W => W,
+		glutin::VirtualKeyCode::X => X,
+		glutin::VirtualKeyCode::Y => Y,
+		glutin::VirtualKeyCode::Z => Z,
+		glutin::VirtualKeyCode::LBracket => BracketLeft,
+		glutin::VirtualKeyCode::RBracket => BracketRight,
+		glutin::VirtualKeyCode::Backslash => Backslash,
+		glutin::VirtualKeyCode::Semicolon => Semicolon,
+		glutin::VirtualKeyCode::Escape => Esc,
+		glutin::VirtualKeyCode::Return => Enter,
+		glutin::VirtualKeyCode::Tab => Tab,
+		glutin::VirtualKeyCode::Back => Backspace,
+		glutin::VirtualKeyCode::Insert => Insert,
+		glutin::VirtualKeyCode::Delete => Delete,
+		glutin::VirtualKeyCode::Right => ArrowRight,
+		glutin::VirtualKeyCode::Left => ArrowLeft,
+		glutin::VirtualKeyCode::Down => ArrowDown,
+		glutin::VirtualKeyCode::Up => ArrowUp,
+		glutin::VirtualKeyCode::PageUp => PgUp,
+		glutin::VirtualKeyCode::PageDown => PgDown,
+		glutin::VirtualKeyCode::Home => Home,
+		glutin::VirtualKeyCode::End => End,
+		glutin::VirtualKeyCode::Capital => CapsLock,
+		glutin::VirtualKeyCode::Scroll => ScrollLock,
+		glutin::VirtualKeyCode::Numlock => NumLock,
+		glutin::VirtualKeyCode::Snapshot => PrintScreen,
+		glutin::VirtualKeyCode::Pause => Pause,
+		glutin::VirtualKeyCode::F1 => F1,
+		glutin::VirtualKeyCode::F2 => F2,
+		glutin::VirtualKeyCode::F3 => F3,
+		glutin::VirtualKeyCode::F4 => F4,
+		glutin::VirtualKeyCode::F5 => F5,
+		glutin::VirtualKeyCode::F6 => F6,
+		glutin::VirtualKeyCode::F7 => F7,
+		glutin::VirtualKeyCode::F8 => F8,
+		glutin::VirtualKeyCode::F9 => F9,
+		glutin::VirtualKeyCode::F10 => F10,
+		glutin::VirtualKeyCode::F11 => F11,
+		glutin::VirtualKeyCode::F12 => F12,
+		glutin::VirtualKeyCode::Numpad0 => NumPad0,
+		glutin::VirtualKeyCode::Numpad1 => NumPad1,
+		glutin::VirtualKeyCode::Numpad2 => NumPad2,
+		glutin::VirtualKeyCode::Numpad3 => NumPad3,
+		glutin::VirtualKeyCode::Numpad4 => NumPad4,
+		glutin::VirtualKeyCode::Numpad5 => NumPad5,
+		glutin::VirtualKeyCode::Numpad6 => NumPad6,
+		glutin::VirtualKeyCode::Numpad7 => NumPad7,
+		glutin::VirtualKeyCode::Numpad8 => NumPad8,
+		glutin::VirtualKeyCode::Numpad9 => NumPad9,
+		glutin::VirtualKeyCode::NumpadComma => NumPadDec,
+		glutin::VirtualKeyCode::Divide => NumPadDiv,
+		glutin::VirtualKeyCode::Multiply => NumPadMult,
+		glutin::VirtualKeyCode::Minus => NumPadSub,
+		glutin::VirtualKeyCode::Add => NumPadAdd,
+		glutin::VirtualKeyCode::NumpadEnter => NumPadEnter,
+		glutin::VirtualKeyCode::NumpadEquals => NumPadEq,
+		glutin::VirtualKeyCode::LShift => LShift,
+		glutin::VirtualKeyCode::LControl => LCtrl,
+		glutin::VirtualKeyCode::LAlt => LAlt,
+		glutin::VirtualKeyCode::LWin => LSuper,
+		glutin::VirtualKeyCode::RShift => RShift,
+		glutin::VirtualKeyCode::RControl => RCtrl,
+		glutin::VirtualKeyCode::RAlt => RAlt,
+		glutin::VirtualKeyCode::RWin => RSuper,
+		_ => Unknown,
+	}
+}
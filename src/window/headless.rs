@@ -0,0 +1,91 @@
+use super::Backend;
+use crate::error::Error;
+use crate::events::{self, EventBox, EventQueue};
+use crate::Float;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// A backend without any surface or GL context.
+///
+/// Events are scripted ahead of time, one batch per frame, which makes it
+/// possible to drive an `Application` deterministically without a display.
+/// Once the scripted frames run out the window is closed, stopping the
+/// application, unless `close_when_exhausted(false)` has been called.
+pub struct HeadlessBackend {
+	frames: VecDeque<Vec<EventBox>>,
+	close_when_exhausted: bool,
+	fixed_delta: Option<Duration>,
+}
+
+impl HeadlessBackend {
+	pub fn new() -> HeadlessBackend {
+		HeadlessBackend {
+			frames: VecDeque::new(),
+			close_when_exhausted: true,
+			fixed_delta: None,
+		}
+	}
+
+	pub fn close_when_exhausted(mut self, close: bool) -> HeadlessBackend {
+		self.close_when_exhausted = close;
+		self
+	}
+
+	/// Makes every frame last exactly `delta`, independent of the wall clock.
+	pub fn with_fixed_delta(mut self, delta: Duration) -> HeadlessBackend {
+		self.fixed_delta = Some(delta);
+		self
+	}
+
+	/// Schedules `events` to be delivered during the next unscripted frame.
+	pub fn push_frame(&mut self, events: Vec<EventBox>) {
+		self.frames.push_back(events);
+	}
+
+	/// Schedules `count` frames without any events.
+	pub fn push_empty_frames(&mut self, count: usize) {
+		for _ in 0..count {
+			self.frames.push_back(Vec::new());
+		}
+	}
+}
+
+impl Default for HeadlessBackend {
+	fn default() -> HeadlessBackend {
+		HeadlessBackend::new()
+	}
+}
+
+impl Backend for HeadlessBackend {
+	fn set_vsync(&mut self, _vsync: bool) -> Result<(), Error> {
+		Ok(())
+	}
+
+	fn poll_events(&mut self, event_queue: &mut EventQueue) {
+		match self.frames.pop_front() {
+			Some(frame) => {
+				for event in frame {
+					event_queue.push(event);
+				}
+			}
+			None => {
+				if self.close_when_exhausted {
+					self.close_when_exhausted = false;
+					event_queue.push(events::WindowClosedEvent::new());
+				}
+			}
+		}
+	}
+
+	fn clear_color(&mut self, _r: Float, _g: Float, _b: Float) {}
+
+	fn clear_screen(&mut self) {}
+
+	fn swap_buffers(&mut self) -> Result<(), Error> {
+		Ok(())
+	}
+
+	fn frame_delta(&mut self) -> Option<Duration> {
+		self.fixed_delta
+	}
+}
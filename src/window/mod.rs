@@ -0,0 +1,405 @@
+use crate::error::Error;
+use crate::events::{self, EventBox, EventListener, EventQueue, Recording};
+use crate::vecs::Vec2;
+use crate::Float;
+use std::cell::Cell;
+use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+pub mod backend;
+pub mod config;
+pub mod cursor;
+pub mod glutin_backend;
+pub mod headless;
+pub mod replay;
+mod swap_interval;
+
+pub use self::{backend::Backend, glutin_backend::GlutinBackend, headless::HeadlessBackend, replay::ReplayBackend};
+pub use self::config::{WindowConfig, WindowIcon, WindowMode};
+pub use self::cursor::CursorIcon;
+
+/// Identifies a window for as long as the program runs, ids aren't reused.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct WindowId(pub u32);
+
+impl fmt::Display for WindowId {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+static NEXT_WINDOW_ID: AtomicU32 = AtomicU32::new(0);
+
+thread_local! {
+	static CURRENT_WINDOW: Cell<Option<WindowId>> = const { Cell::new(None) };
+}
+
+/// The window whose events are being dispatched or which is being rendered
+/// to, i.e. the window an event came from. `None` outside of those, e.g.
+/// during updates, and for events that don't come from a window, like those
+/// sent by gamepads or published on an `EventBus`.
+pub fn get_current_window() -> Option<WindowId> {
+	CURRENT_WINDOW.with(|current| current.get())
+}
+
+pub(crate) fn set_current_window(id: Option<WindowId>) {
+	CURRENT_WINDOW.with(|current| current.set(id));
+}
+
+/// Takes the id of a window that is created later on
+pub(crate) fn reserve_window_id() -> WindowId {
+	WindowId(NEXT_WINDOW_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+pub struct Window {
+	id: WindowId,
+	config: WindowConfig,
+	text_input: bool,
+	focused: bool,
+	minimized: bool,
+	scale_factor: Float,
+	framebuffer_size: (u32, u32),
+	event_queue: EventQueue,
+	backend: Box<dyn Backend>,
+}
+
+impl Window {
+	pub fn new(config: WindowConfig) -> Result<Window, Error> {
+		Window::with_id(reserve_window_id(), config)
+	}
+
+	pub(crate) fn with_id(id: WindowId, config: WindowConfig) -> Result<Window, Error> {
+		let backend = GlutinBackend::new(&config)?;
+		Ok(Window::from_backend(id, config, Box::new(backend)))
+	}
+
+	pub fn with_backend(config: WindowConfig, backend: Box<dyn Backend>) -> Window {
+		Window::from_backend(reserve_window_id(), config, backend)
+	}
+
+	fn from_backend(id: WindowId, config: WindowConfig, backend: Box<dyn Backend>) -> Window {
+		let scale_factor = backend.get_scale_factor();
+		let framebuffer_size = (
+			to_physical(config.get_width(), scale_factor),
+			to_physical(config.get_height(), scale_factor),
+		);
+		Window {
+			id,
+			config,
+			text_input: false,
+			focused: true,
+			minimized: false,
+			scale_factor,
+			framebuffer_size,
+			event_queue: EventQueue::new(),
+			backend,
+		}
+	}
+
+	pub fn headless(config: WindowConfig, backend: HeadlessBackend) -> Window {
+		crate::rame_core_info!(
+			"created headless window \"{}\" ({}x{})",
+			config.get_title(),
+			config.get_width(),
+			config.get_height()
+		);
+		Window::with_backend(config, Box::new(backend))
+	}
+
+	/// A headless window that plays back `recording`, with the title and
+	/// size of the recorded window.
+	pub fn replay(recording: Recording) -> Window {
+		crate::rame_core_info!("replaying {} recorded frames", recording.frames.len());
+		let config = WindowConfig::new(&recording.title, recording.width, recording.height);
+		// The backend reports the recorded scale factor
+		Window::with_backend(config, Box::new(ReplayBackend::new(recording)))
+	}
+
+	pub fn get_id(&self) -> WindowId {
+		self.id
+	}
+
+	pub fn get_config(&self) -> &WindowConfig {
+		&self.config
+	}
+
+	pub fn get_title(&self) -> &str {
+		self.config.get_title()
+	}
+
+	/// The width inside the window's borders in logical pixels, see
+	/// `get_physical_width`
+	pub fn get_width(&self) -> u32 {
+		self.config.get_width()
+	}
+
+	pub fn get_height(&self) -> u32 {
+		self.config.get_height()
+	}
+
+	/// The number of physical pixels per logical pixel, e.g. 2 on most HiDPI
+	/// displays
+	pub fn get_scale_factor(&self) -> Float {
+		self.scale_factor
+	}
+
+	/// The width in physical pixels, i.e. the width of the framebuffer
+	pub fn get_physical_width(&self) -> u32 {
+		to_physical(self.get_width(), self.scale_factor)
+	}
+
+	pub fn get_physical_height(&self) -> u32 {
+		to_physical(self.get_height(), self.scale_factor)
+	}
+
+	pub fn get_vsync(&self) -> bool {
+		self.config.get_vsync()
+	}
+
+	/// `None` until the window has been placed explicitly or moved
+	pub fn get_position(&self) -> Option<(i32, i32)> {
+		self.config.get_position()
+	}
+
+	pub fn is_resizable(&self) -> bool {
+		self.config.is_resizable()
+	}
+
+	pub fn get_mode(&self) -> WindowMode {
+		self.config.get_mode()
+	}
+
+	pub fn get_min_size(&self) -> Option<(u32, u32)> {
+		self.config.get_min_size()
+	}
+
+	pub fn get_max_size(&self) -> Option<(u32, u32)> {
+		self.config.get_max_size()
+	}
+
+	pub fn get_cursor_icon(&self) -> CursorIcon {
+		self.config.get_cursor_icon()
+	}
+
+	pub fn is_cursor_visible(&self) -> bool {
+		self.config.is_cursor_visible()
+	}
+
+	pub fn is_cursor_grabbed(&self) -> bool {
+		self.config.is_cursor_grabbed()
+	}
+
+	/// Whether the cursor is hidden and grabbed, see `set_relative_mouse`
+	pub fn is_relative_mouse(&self) -> bool {
+		!self.is_cursor_visible() && self.is_cursor_grabbed()
+	}
+
+	/// The size of the framebuffer in physical pixels. Unlike the physical
+	/// size of the window it isn't changed while the window is minimized.
+	pub fn get_framebuffer_size(&self) -> (u32, u32) {
+		self.framebuffer_size
+	}
+
+	pub fn is_text_input_enabled(&self) -> bool {
+		self.text_input
+	}
+
+	pub fn is_focused(&self) -> bool {
+		self.focused
+	}
+
+	/// Always false on platforms other than Windows, see
+	/// `WindowMinimizedEvent`
+	pub fn is_minimized(&self) -> bool {
+		self.minimized
+	}
+
+	/// Changes the swap interval of the window's GL context. Platforms
+	/// without a swap control extension, like macOS, recreate the window and
+	/// its context instead. Textures, buffers, shaders and other shareable
+	/// objects stay valid then, but container objects like vertex arrays and
+	/// framebuffers aren't shared and have to be recreated.
+	pub fn set_vsync(&mut self, vsync: bool) -> Result<(), Error> {
+		if vsync != self.config.get_vsync() {
+			self.backend.set_vsync(vsync)?;
+			self.config.set_vsync(vsync);
+		}
+		Ok(())
+	}
+
+	pub fn set_title(&mut self, title: &str) {
+		self.config.set_title(title);
+		self.backend.set_title(title);
+	}
+
+	/// Resizes the area inside the window's borders.
+	pub fn set_size(&mut self, width: u32, height: u32) {
+		self.config.set_size(width, height);
+		self.backend.set_size(width, height);
+	}
+
+	/// Moves the top left corner of the window to `x`, `y` on the desktop.
+	pub fn set_position(&mut self, x: i32, y: i32) {
+		self.config.set_position(x, y);
+		self.backend.set_position(x, y);
+	}
+
+	pub fn set_resizable(&mut self, resizable: bool) {
+		self.config.set_resizable(resizable);
+		self.backend.set_resizable(resizable);
+	}
+
+	pub fn set_mode(&mut self, mode: WindowMode) {
+		if mode != self.config.get_mode() {
+			self.config.set_mode(mode);
+			self.backend.set_mode(mode);
+		}
+	}
+
+	pub fn set_min_size(&mut self, size: Option<(u32, u32)>) {
+		self.config.set_min_size(size);
+		self.backend.set_min_size(size);
+	}
+
+	pub fn set_max_size(&mut self, size: Option<(u32, u32)>) {
+		self.config.set_max_size(size);
+		self.backend.set_max_size(size);
+	}
+
+	pub fn set_icon(&mut self, icon: Option<WindowIcon>) {
+		self.backend.set_icon(icon.as_ref());
+		self.config.set_icon(icon);
+	}
+
+	pub fn set_cursor_icon(&mut self, icon: CursorIcon) {
+		if icon != self.config.get_cursor_icon() {
+			self.config.set_cursor_icon(icon);
+			self.backend.set_cursor_icon(icon);
+		}
+	}
+
+	/// Hides or shows the cursor while it's over the window.
+	pub fn set_cursor_visible(&mut self, visible: bool) {
+		if visible != self.config.is_cursor_visible() {
+			self.config.set_cursor_visible(visible);
+			self.backend.set_cursor_visible(visible);
+		}
+	}
+
+	/// Keeps the cursor from leaving the window while it's focused. Fails on
+	/// platforms that don't support it.
+	pub fn set_cursor_grabbed(&mut self, grabbed: bool) -> Result<(), Error> {
+		if grabbed != self.config.is_cursor_grabbed() {
+			self.backend.set_cursor_grabbed(grabbed)?;
+			self.config.set_cursor_grabbed(grabbed);
+		}
+		Ok(())
+	}
+
+	/// Hides and grabs the cursor, e.g. for a first person camera driven by
+	/// `MouseMotionEvent`s, or releases it again.
+	pub fn set_relative_mouse(&mut self, enabled: bool) -> Result<(), Error> {
+		self.set_cursor_grabbed(enabled)?;
+		self.set_cursor_visible(!enabled);
+		Ok(())
+	}
+
+	/// Moves the cursor to `position` within the window. A mouse moved event
+	/// is delivered with the next events, whether or not the platform
+	/// reports one.
+	pub fn set_cursor_position(&mut self, position: Vec2) -> Result<(), Error> {
+		self.backend.set_cursor_position(position)?;
+		self.event_queue.push(events::MouseMovedEvent::synthetic(position, self.scale_factor));
+		Ok(())
+	}
+
+	pub fn pop_event(&mut self) -> Option<EventBox> {
+		self.event_queue.next()
+	}
+
+	/// Whether redundant events, like several mouse moves within a frame,
+	/// are merged. Disabled by default.
+	pub fn set_event_coalescing(&mut self, coalescing: bool) {
+		self.event_queue.set_coalescing(coalescing);
+	}
+
+	pub fn set_text_input(&mut self, enabled: bool) {
+		if self.text_input != enabled {
+			self.text_input = enabled;
+			self.backend.set_text_input(enabled);
+		}
+	}
+
+	pub fn on_update(&mut self) {
+		let mut event_queue = EventQueue::new();
+		self.backend.poll_events(&mut event_queue);
+
+		// The window listens to its own events first to keep its state in sync
+		for mut event in event_queue {
+			event.dispatch(self);
+			self.event_queue.push(event);
+		}
+
+		let size = (self.get_physical_width(), self.get_physical_height());
+		let empty = size.0 == 0 || size.1 == 0;
+		if size != self.framebuffer_size && !empty && !self.minimized {
+			// The viewport is part of the context's state
+			if let Err(err) = self.backend.make_current() {
+				crate::rame_core_error!("failed to resize framebuffer of window {}: {}", self.id, err);
+				return;
+			}
+			self.framebuffer_size = size;
+			self.backend.resize_framebuffer(size.0, size.1);
+			self.event_queue.push(events::FramebufferResizedEvent::new(size.0, size.1));
+		}
+	}
+
+	/// Makes GL calls go to this window's context.
+	pub fn make_current(&mut self) -> Result<(), Error> {
+		self.backend.make_current()
+	}
+
+	pub fn clear_color(&mut self, r: Float, g: Float, b: Float) {
+		self.backend.clear_color(r, g, b);
+	}
+
+	pub fn clear_screen(&mut self) {
+		self.backend.clear_screen();
+	}
+
+	pub fn swap_buffers(&mut self) -> Result<(), Error> {
+		self.backend.swap_buffers()
+	}
+
+	pub fn frame_delta(&mut self) -> Option<Duration> {
+		self.backend.frame_delta()
+	}
+}
+
+impl EventListener for Window {
+	fn on_window_resize(&mut self, width: u32, height: u32, scale_factor: Float) {
+		crate::rame_core_debug!("window \"{}\" resized to {}x{}", self.get_title(), width, height);
+		self.config.set_size(width, height);
+		self.scale_factor = scale_factor;
+	}
+	fn on_window_move(&mut self, x: i32, y: i32) {
+		self.config.set_position(x, y);
+	}
+	fn on_window_focus(&mut self, focused: bool) {
+		self.focused = focused;
+	}
+	fn on_window_minimize(&mut self, minimized: bool) {
+		crate::rame_core_debug!("window \"{}\" {}", self.get_title(), if minimized { "minimized" } else { "restored" });
+		self.minimized = minimized;
+	}
+	fn on_dpi_change(&mut self, scale_factor: Float) {
+		crate::rame_core_debug!("window \"{}\" scale factor changed to {}", self.get_title(), scale_factor);
+		self.scale_factor = scale_factor;
+	}
+}
+
+/// Converts a length in logical pixels to physical pixels.
+pub(crate) fn to_physical(logical: u32, scale_factor: Float) -> u32 {
+	(Float::from(logical) * scale_factor).round() as u32
+}
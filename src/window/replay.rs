@@ -0,0 +1,80 @@
+use super::Backend;
+use crate::error::Error;
+use crate::events::{self, EventQueue, RecordedFrame, Recording};
+use crate::Float;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// A backend that plays back a `Recording` instead of reading platform
+/// events, without any surface or GL context.
+///
+/// Each frame delivers the recorded events and lasts exactly as long as the
+/// recorded frame did. Once the recording runs out the window is closed.
+pub struct ReplayBackend {
+	frames: VecDeque<RecordedFrame>,
+	scale_factor: Float,
+	current: Option<RecordedFrame>,
+	closed: bool,
+}
+
+impl ReplayBackend {
+	pub fn new(recording: Recording) -> ReplayBackend {
+		ReplayBackend {
+			frames: recording.frames.into(),
+			scale_factor: recording.scale_factor,
+			current: None,
+			closed: false,
+		}
+	}
+
+	/// Moves on to the next recorded frame, unless it was already started by
+	/// `frame_delta`.
+	fn load_frame(&mut self) {
+		if self.current.is_none() {
+			self.current = self.frames.pop_front();
+		}
+	}
+}
+
+impl Backend for ReplayBackend {
+	fn set_vsync(&mut self, _vsync: bool) -> Result<(), Error> {
+		Ok(())
+	}
+
+	fn get_scale_factor(&self) -> Float {
+		self.scale_factor
+	}
+
+	fn poll_events(&mut self, event_queue: &mut EventQueue) {
+		self.load_frame();
+		match self.current.take() {
+			Some(frame) => {
+				for event in frame.events {
+					event_queue.push(event);
+				}
+			}
+			None => {
+				if !self.closed {
+					self.closed = true;
+					event_queue.push(events::WindowClosedEvent::new());
+				}
+			}
+		}
+	}
+
+	fn clear_color(&mut self, _r: Float, _g: Float, _b: Float) {}
+
+	fn clear_screen(&mut self) {}
+
+	fn swap_buffers(&mut self) -> Result<(), Error> {
+		Ok(())
+	}
+
+	fn frame_delta(&mut self) -> Option<Duration> {
+		self.load_frame();
+		match &self.current {
+			Some(frame) => Some(frame.delta),
+			None => Some(Duration::from_secs(0)),
+		}
+	}
+}
@@ -0,0 +1,115 @@
+//! Changing the swap interval of an existing context through the swap
+//! control extension of the platform's GL API.
+
+/// Sets the swap interval of `glutin_window`'s context, which has to be
+/// current. Returns false if the platform has no swap control extension,
+/// e.g. on macOS.
+pub(crate) fn set_swap_interval(glutin_window: &glutin::GlWindow, interval: i32) -> bool {
+	unsafe { platform::set_swap_interval(glutin_window, interval) }
+}
+
+fn get_proc_address(glutin_window: &glutin::GlWindow, name: &str) -> Option<*const ()> {
+	use glutin::GlContext;
+	let address = glutin_window.get_proc_address(name);
+	if address.is_null() {
+		None
+	} else {
+		Some(address)
+	}
+}
+
+#[cfg(windows)]
+mod platform {
+	use std::mem;
+	use std::os::raw::c_int;
+
+	pub(super) unsafe fn set_swap_interval(glutin_window: &glutin::GlWindow, interval: i32) -> bool {
+		// Unlike glXGetProcAddress, this gives null for missing extensions
+		match super::get_proc_address(glutin_window, "wglSwapIntervalEXT") {
+			Some(address) => {
+				let swap_interval = mem::transmute::<*const (), unsafe extern "system" fn(c_int) -> c_int>(address);
+				swap_interval(interval) != 0
+			}
+			None => false,
+		}
+	}
+}
+
+#[cfg(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "openbsd"))]
+mod platform {
+	use super::get_proc_address;
+	use glutin::os::unix::{RawHandle, WindowExt};
+	use glutin::os::GlContextExt;
+	use std::ffi::CStr;
+	use std::mem;
+	use std::os::raw::{c_char, c_int, c_uint, c_ulong, c_void};
+
+	pub(super) unsafe fn set_swap_interval(glutin_window: &glutin::GlWindow, interval: i32) -> bool {
+		match glutin_window.context().raw_handle() {
+			RawHandle::Glx(_) => set_glx_swap_interval(glutin_window, interval),
+			RawHandle::Egl(_) => set_egl_swap_interval(glutin_window, interval),
+		}
+	}
+
+	// glXGetProcAddress gives an address for any name, so the extensions have
+	// to be checked before calling anything it returns
+	unsafe fn set_glx_swap_interval(glutin_window: &glutin::GlWindow, interval: i32) -> bool {
+		let display = glutin_window.get_xlib_display();
+		let drawable = glutin_window.get_xlib_window();
+		let screen = glutin_window.get_xlib_screen_id();
+		let query = get_proc_address(glutin_window, "glXQueryExtensionsString");
+		let (display, drawable, screen, query) = match (display, drawable, screen, query) {
+			(Some(display), Some(drawable), Some(screen), Some(query)) => (display, drawable, screen, query),
+			_ => return false,
+		};
+		let query = mem::transmute::<*const (), unsafe extern "C" fn(*mut c_void, c_int) -> *const c_char>(query);
+		let extensions = query(display, screen);
+		if extensions.is_null() {
+			return false;
+		}
+		let extensions = CStr::from_ptr(extensions).to_string_lossy();
+		let has_extension = |name: &str| extensions.split_whitespace().any(|extension| extension == name);
+
+		if has_extension("GLX_EXT_swap_control") {
+			if let Some(address) = get_proc_address(glutin_window, "glXSwapIntervalEXT") {
+				let swap_interval =
+					mem::transmute::<*const (), unsafe extern "C" fn(*mut c_void, c_ulong, c_int)>(address);
+				swap_interval(display, drawable, interval);
+				return true;
+			}
+		}
+		if has_extension("GLX_MESA_swap_control") {
+			if let Some(address) = get_proc_address(glutin_window, "glXSwapIntervalMESA") {
+				let swap_interval = mem::transmute::<*const (), unsafe extern "C" fn(c_uint) -> c_int>(address);
+				return swap_interval(interval as c_uint) == 0;
+			}
+		}
+		false
+	}
+
+	// Both are core since EGL 1.1, so there's no extension to check for
+	unsafe fn set_egl_swap_interval(glutin_window: &glutin::GlWindow, interval: i32) -> bool {
+		let get_display = get_proc_address(glutin_window, "eglGetCurrentDisplay");
+		let swap_interval = get_proc_address(glutin_window, "eglSwapInterval");
+		let (get_display, swap_interval) = match (get_display, swap_interval) {
+			(Some(get_display), Some(swap_interval)) => (get_display, swap_interval),
+			_ => return false,
+		};
+		let get_display = mem::transmute::<*const (), unsafe extern "C" fn() -> *mut c_void>(get_display);
+		let swap_interval = mem::transmute::<*const (), unsafe extern "C" fn(*mut c_void, i32) -> c_uint>(swap_interval);
+		swap_interval(get_display(), interval) != 0
+	}
+}
+
+#[cfg(not(any(
+	windows,
+	target_os = "linux",
+	target_os = "dragonfly",
+	target_os = "freebsd",
+	target_os = "openbsd"
+)))]
+mod platform {
+	pub(super) unsafe fn set_swap_interval(_glutin_window: &glutin::GlWindow, _interval: i32) -> bool {
+		false
+	}
+}